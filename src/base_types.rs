@@ -4,10 +4,18 @@
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
+/// Address of the Sui framework package (`0x2`)
+pub const SUI_FRAMEWORK_ADDRESS: SuiAddress = {
+    let mut address = [0u8; 32];
+    address[31] = 2;
+    SuiAddress(address)
+};
+
 /// SuiAddress is a 32-byte account address.
 #[derive(
     Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Hash, Default, Debug, Serialize, Deserialize,
 )]
+#[repr(transparent)]
 pub struct SuiAddress([u8; 32]);
 
 impl SuiAddress {
@@ -33,8 +41,29 @@ impl SuiAddress {
         format!("0x{}", hex::encode(self.0))
     }
 
-    pub fn to_string(&self) -> String {
-        self.to_hex_literal()
+    /// Parse a `0x`-prefixed hex address, left-padding short forms such as `0x2`.
+    ///
+    /// Short forms may have at most `MAX_SHORT_HEX_LITERAL_LEN` digits; anything longer must
+    /// spell out all 64, so an address missing a nibble is rejected rather than shifted.
+    pub fn from_hex_literal(literal: &str) -> Result<Self, anyhow::Error> {
+        let hex_str = literal
+            .strip_prefix("0x")
+            .ok_or_else(|| anyhow::anyhow!("Address literal must start with 0x: {}", literal))?;
+        if hex_str.is_empty() {
+            return Err(anyhow::anyhow!("Invalid address literal: {}", literal));
+        }
+        if hex_str.len() > MAX_SHORT_HEX_LITERAL_LEN && hex_str.len() != 64 {
+            return Err(invalid_length("address literal", 64, hex_str.len()));
+        }
+        Self::from_str(&format!("{:0>64}", hex_str))
+    }
+
+    /// Write the `0x`-prefixed hex form without allocating
+    pub fn write_hex<W: fmt::Write>(&self, w: &mut W) -> fmt::Result {
+        let mut buf = [0u8; 66];
+        buf[..2].copy_from_slice(b"0x");
+        hex::encode_to_slice(self.0, &mut buf[2..]).map_err(|_| fmt::Error)?;
+        w.write_str(std::str::from_utf8(&buf).map_err(|_| fmt::Error)?)
     }
 }
 
@@ -44,6 +73,26 @@ impl From<[u8; 32]> for SuiAddress {
     }
 }
 
+impl TryFrom<&[u8]> for SuiAddress {
+    type Error = anyhow::Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        let address =
+            <[u8; 32]>::try_from(bytes).map_err(|_| invalid_length("address", 32, bytes.len()))?;
+        Ok(SuiAddress(address))
+    }
+}
+
+impl TryFrom<Vec<u8>> for SuiAddress {
+    type Error = anyhow::Error;
+
+    fn try_from(bytes: Vec<u8>) -> Result<Self, Self::Error> {
+        let address = <[u8; 32]>::try_from(bytes)
+            .map_err(|bytes| invalid_length("address", 32, bytes.len()))?;
+        Ok(SuiAddress(address))
+    }
+}
+
 impl From<SuiAddress> for [u8; 32] {
     fn from(address: SuiAddress) -> Self {
         address.0
@@ -51,30 +100,15 @@ impl From<SuiAddress> for [u8; 32] {
 }
 
 impl From<&crate::crypto::PublicKey> for SuiAddress {
+    /// Blake2b-256 over the scheme flag followed by the public key bytes, as Sui derives
+    /// single-key addresses
     fn from(public_key: &crate::crypto::PublicKey) -> Self {
-        match public_key {
-            crate::crypto::PublicKey::Ed25519(bytes) => {
-                // Take first 32 bytes for address
-                let mut address = [0u8; 32];
-                let len = std::cmp::min(bytes.len(), 32);
-                address[..len].copy_from_slice(&bytes[..len]);
-                SuiAddress(address)
-            }
-            crate::crypto::PublicKey::Secp256k1(bytes) => {
-                // Take first 32 bytes for address
-                let mut address = [0u8; 32];
-                let len = std::cmp::min(bytes.len(), 32);
-                address[..len].copy_from_slice(&bytes[..len]);
-                SuiAddress(address)
-            }
-            crate::crypto::PublicKey::Secp256r1(bytes) => {
-                // Take first 32 bytes for address
-                let mut address = [0u8; 32];
-                let len = std::cmp::min(bytes.len(), 32);
-                address[..len].copy_from_slice(&bytes[..len]);
-                SuiAddress(address)
-            }
-        }
+        use blake2::Digest as _;
+
+        let mut hasher = crate::crypto::DefaultHash::new();
+        hasher.update([public_key.flag()]);
+        hasher.update(public_key.as_bytes());
+        SuiAddress(hasher.finalize().into())
     }
 }
 
@@ -86,7 +120,7 @@ impl AsRef<[u8]> for SuiAddress {
 
 impl fmt::Display for SuiAddress {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.to_hex_literal())
+        self.write_hex(f)
     }
 }
 
@@ -94,15 +128,36 @@ impl FromStr for SuiAddress {
     type Err = anyhow::Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let s = s.strip_prefix("0x").unwrap_or(s);
-        let bytes = hex::decode(s)?;
-        if bytes.len() != 32 {
-            return Err(anyhow::anyhow!("Address must be 32 bytes"));
-        }
+        validate_hex_address_str(s)?;
         let mut address = [0u8; 32];
-        address.copy_from_slice(&bytes);
+        hex::decode_to_slice(s.strip_prefix("0x").unwrap_or(s), &mut address)?;
         Ok(SuiAddress(address))
     }
 }
 
+/// Most hex digits `SuiAddress::from_hex_literal` accepts without all 64 being present
+pub const MAX_SHORT_HEX_LITERAL_LEN: usize = 32;
+
+/// Error for an input whose length does not match what its type requires
+pub(crate) fn invalid_length(what: &str, expected: usize, actual: usize) -> anyhow::Error {
+    anyhow::anyhow!(
+        "Invalid {} length: expected {}, got {}",
+        what,
+        expected,
+        actual
+    )
+}
+
+/// Check that `s` is exactly 64 hex digits, optionally `0x`-prefixed, before any decoding
+pub fn validate_hex_address_str(s: &str) -> Result<(), anyhow::Error> {
+    let hex_str = s.strip_prefix("0x").unwrap_or(s);
+    if hex_str.len() != 64 {
+        return Err(invalid_length("hex", 64, hex_str.len()));
+    }
+    if !hex_str.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return Err(anyhow::anyhow!("Invalid hex address: {}", s));
+    }
+    Ok(())
+}
+
 use std::str::FromStr;
@@ -0,0 +1,84 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::crypto::PublicKey;
+
+/// Voting power held by a single committee member
+pub type StakeUnit = u64;
+
+/// Committee of authorities with their voting power
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Committee {
+    pub members: Vec<(PublicKey, StakeUnit)>,
+}
+
+/// Membership changes between two committees
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct CommitteeDiff {
+    pub added: Vec<(PublicKey, StakeUnit)>,
+    pub removed: Vec<(PublicKey, StakeUnit)>,
+    /// Members present in both committees as `(key, old_power, new_power)`
+    pub reweighted: Vec<(PublicKey, StakeUnit, StakeUnit)>,
+}
+
+impl CommitteeDiff {
+    /// Whether the two committees are identical
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.reweighted.is_empty()
+    }
+}
+
+impl Committee {
+    /// Create a new committee
+    pub fn new(members: Vec<(PublicKey, StakeUnit)>) -> Self {
+        Self { members }
+    }
+
+    /// Sum of all members' voting power, saturating at `StakeUnit::MAX`
+    pub fn total_power(&self) -> StakeUnit {
+        StakeUnit::try_from(self.total_power_wide()).unwrap_or(StakeUnit::MAX)
+    }
+
+    /// Voting power needed for a quorum (2f+1), saturating at `StakeUnit::MAX`
+    pub fn quorum_threshold(&self) -> StakeUnit {
+        StakeUnit::try_from(2 * self.total_power_wide() / 3 + 1).unwrap_or(StakeUnit::MAX)
+    }
+
+    /// Exact sum of voting power, widened so large committees cannot overflow
+    fn total_power_wide(&self) -> u128 {
+        self.members.iter().map(|(_, power)| *power as u128).sum()
+    }
+
+    /// Voting power of a member, if present
+    pub fn power_of(&self, public_key: &PublicKey) -> Option<StakeUnit> {
+        self.members
+            .iter()
+            .find(|(key, _)| key == public_key)
+            .map(|(_, power)| *power)
+    }
+
+    /// Whether the key is a committee member
+    pub fn contains(&self, public_key: &PublicKey) -> bool {
+        self.power_of(public_key).is_some()
+    }
+
+    /// Report members added, removed, or reweighted going from `self` to `other`
+    pub fn diff(&self, other: &Committee) -> CommitteeDiff {
+        let mut diff = CommitteeDiff::default();
+        for (key, power) in &self.members {
+            match other.power_of(key) {
+                None => diff.removed.push((key.clone(), *power)),
+                Some(new_power) if new_power != *power => {
+                    diff.reweighted.push((key.clone(), *power, new_power))
+                }
+                Some(_) => {}
+            }
+        }
+        for (key, power) in &other.members {
+            if !self.contains(key) {
+                diff.added.push((key.clone(), *power));
+            }
+        }
+        diff
+    }
+}
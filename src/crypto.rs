@@ -1,14 +1,47 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::base_types::SuiAddress;
+use crate::transaction::{intent_signing_bytes, Intent, IntentMessage, IntentScope};
+use base64::Engine;
+use blake2::Digest as _;
+use k256::elliptic_curve::sec1::ToEncodedPoint;
 use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::path::Path;
+
+/// Default hash function used for digests and derived ids (Blake2b-256)
+pub type DefaultHash = blake2::Blake2b<blake2::digest::consts::U32>;
 
 /// Empty signature info for unsigned transactions
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct EmptySignInfo {}
 
+/// Authentication carried by an envelope, inspectable without knowing its concrete type
+pub trait AuthInfo {
+    /// Number of signatures attached
+    fn signature_count(&self) -> usize;
+
+    /// Whether any signature is attached
+    fn is_signed(&self) -> bool {
+        self.signature_count() > 0
+    }
+}
+
+impl AuthInfo for EmptySignInfo {
+    fn signature_count(&self) -> usize {
+        0
+    }
+}
+
+impl AuthInfo for Vec<BasicSignature> {
+    fn signature_count(&self) -> usize {
+        self.len()
+    }
+}
+
 /// SuiKeyPair represents a key pair for signing transactions
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub enum SuiKeyPair {
     Ed25519(Vec<u8>),
     Secp256k1(Vec<u8>),
@@ -16,14 +49,83 @@ pub enum SuiKeyPair {
 }
 
 impl SuiKeyPair {
+    /// The public key derived from the secret.
+    ///
+    /// Panics if the variant was built directly from bytes that are not a valid secret key
+    /// for its scheme; `from_bytes` and the other constructors reject those.
     pub fn public(&self) -> PublicKey {
+        self.try_public()
+            .expect("SuiKeyPair should hold a valid secret key")
+    }
+
+    /// Derive the public key, failing if the secret is not a valid 32-byte key for its scheme
+    fn try_public(&self) -> Result<PublicKey, eyre::Report> {
+        let secret = match self {
+            SuiKeyPair::Ed25519(secret) => secret,
+            SuiKeyPair::Secp256k1(secret) => secret,
+            SuiKeyPair::Secp256r1(secret) => secret,
+        };
+        let secret: &[u8; 32] = secret
+            .as_slice()
+            .try_into()
+            .map_err(|_| eyre::eyre!("Invalid secret key length {}", secret.len()))?;
         match self {
-            SuiKeyPair::Ed25519(_) => PublicKey::Ed25519([0u8; 32]),
-            SuiKeyPair::Secp256k1(_) => PublicKey::Secp256k1([0u8; 33]),
-            SuiKeyPair::Secp256r1(_) => PublicKey::Secp256r1([0u8; 33]),
+            SuiKeyPair::Ed25519(_) => Ok(PublicKey::Ed25519(
+                ed25519_dalek::SigningKey::from_bytes(secret)
+                    .verifying_key()
+                    .to_bytes(),
+            )),
+            SuiKeyPair::Secp256k1(_) => {
+                let secret = k256::SecretKey::from_slice(secret)
+                    .map_err(|_| eyre::eyre!("Invalid Secp256k1 secret key"))?;
+                Ok(PublicKey::Secp256k1(compressed_point(
+                    secret.public_key().to_encoded_point(true).as_bytes(),
+                )))
+            }
+            SuiKeyPair::Secp256r1(_) => {
+                let secret = p256::SecretKey::from_slice(secret)
+                    .map_err(|_| eyre::eyre!("Invalid Secp256r1 secret key"))?;
+                Ok(PublicKey::Secp256r1(compressed_point(
+                    secret.public_key().to_encoded_point(true).as_bytes(),
+                )))
+            }
         }
     }
 
+    /// Sign a 32-byte digest: Ed25519 directly, ECDSA over its SHA-256 with a low-S result
+    fn sign_digest(&self, digest: &[u8; 32]) -> Result<BasicSignature, eyre::Report> {
+        use k256::ecdsa::signature::Signer;
+
+        let (scheme, signature_bytes) = match self {
+            SuiKeyPair::Ed25519(secret) => {
+                let secret: &[u8; 32] = secret.as_slice().try_into()?;
+                let signing_key = ed25519_dalek::SigningKey::from_bytes(secret);
+                (SignatureScheme::ED25519, signing_key.sign(digest).to_vec())
+            }
+            SuiKeyPair::Secp256k1(secret) => {
+                let signing_key = k256::ecdsa::SigningKey::from_slice(secret)?;
+                let signature: k256::ecdsa::Signature = signing_key.sign(digest);
+                (SignatureScheme::Secp256k1, signature.to_bytes().to_vec())
+            }
+            SuiKeyPair::Secp256r1(secret) => {
+                let signing_key = p256::ecdsa::SigningKey::from_slice(secret)?;
+                let signature: p256::ecdsa::Signature = signing_key.sign(digest);
+                let signature = signature.normalize_s().unwrap_or(signature);
+                (SignatureScheme::Secp256r1, signature.to_bytes().to_vec())
+            }
+        };
+        Ok(BasicSignature {
+            scheme,
+            signature_bytes,
+        })
+    }
+
+    /// The key's scheme and derived address, without any secret material
+    pub fn public_identity(&self) -> (SignatureScheme, SuiAddress) {
+        let public_key = self.public();
+        (public_key.scheme(), SuiAddress::from(&public_key))
+    }
+
     pub fn copy(&self) -> Self {
         match self {
             SuiKeyPair::Ed25519(kp) => SuiKeyPair::Ed25519(kp.clone()),
@@ -34,7 +136,11 @@ impl SuiKeyPair {
 
     pub fn to_bytes(&self) -> Vec<u8> {
         let mut bytes: Vec<u8> = Vec::new();
-        bytes.push(self.public().flag());
+        bytes.push(match self {
+            SuiKeyPair::Ed25519(_) => SignatureScheme::ED25519.flag(),
+            SuiKeyPair::Secp256k1(_) => SignatureScheme::Secp256k1.flag(),
+            SuiKeyPair::Secp256r1(_) => SignatureScheme::Secp256r1.flag(),
+        });
 
         match self {
             SuiKeyPair::Ed25519(kp) => {
@@ -51,7 +157,7 @@ impl SuiKeyPair {
     }
 
     pub fn from_bytes(bytes: &[u8]) -> Result<Self, eyre::Report> {
-        match SignatureScheme::from_flag_byte(bytes.first().ok_or_else(|| eyre::eyre!("Invalid length"))?)
+        let keypair = match SignatureScheme::from_flag_byte(bytes.first().ok_or_else(|| eyre::eyre!("Invalid length"))?)
         {
             Ok(x) => match x {
                 SignatureScheme::ED25519 => Ok(SuiKeyPair::Ed25519(
@@ -69,10 +175,119 @@ impl SuiKeyPair {
                 }
             },
             _ => Err(eyre::eyre!("Invalid bytes")),
+        }?;
+        keypair.try_public()?;
+        Ok(keypair)
+    }
+
+    /// Create an Ed25519 key pair from a 32-byte secret key
+    pub fn from_ed25519_secret(secret: &[u8; 32]) -> Self {
+        SuiKeyPair::Ed25519(secret.to_vec())
+    }
+
+    /// Create an Ed25519 key pair from the expanded `secret || public` layout.
+    ///
+    /// Only the secret half is kept. The public half is discarded without being checked, so
+    /// a mismatched input is not detected; `public()` always derives from the secret.
+    pub fn from_ed25519_expanded(bytes: &[u8; 64]) -> Self {
+        let (secret, _public) = bytes.split_at(32);
+        SuiKeyPair::Ed25519(secret.to_vec())
+    }
+
+    /// Derive the Ed25519 key for `account` from a BIP39 mnemonic, with an empty passphrase,
+    /// via SLIP-10 along `m/44'/784'/{account}'/0'/0'` as the Sui CLI and wallets do
+    #[cfg(feature = "mnemonic")]
+    pub fn from_mnemonic_ed25519(phrase: &str, account: u32) -> Result<Self, eyre::Report> {
+        let mnemonic = bip39::Mnemonic::parse_normalized(phrase)
+            .map_err(|e| eyre::eyre!("Invalid BIP39 mnemonic: {}", e))?;
+        let seed = mnemonic.to_seed_normalized("");
+
+        let mut node = slip10_hmac(b"ed25519 seed", &seed);
+        for index in [44, 784, account, 0, 0] {
+            if index >= SLIP10_HARDENED_OFFSET {
+                return Err(eyre::eyre!("Derivation index {} is out of range", index));
+            }
+            let mut data = Vec::with_capacity(37);
+            data.push(0);
+            data.extend_from_slice(&node[..32]);
+            data.extend_from_slice(&(index | SLIP10_HARDENED_OFFSET).to_be_bytes());
+            node = slip10_hmac(&node[32..], &data);
         }
+        Ok(SuiKeyPair::Ed25519(node[..32].to_vec()))
+    }
+
+    /// Load keys from a `sui.keystore` file: a JSON array of base64 flag-prefixed keys
+    pub fn load_keystore<P: AsRef<Path>>(path: P) -> Result<Vec<SuiKeyPair>, eyre::Report> {
+        let contents = std::fs::read_to_string(path)?;
+        let entries: Vec<String> = serde_json::from_str(&contents)?;
+        entries
+            .iter()
+            .enumerate()
+            .map(|(index, entry)| {
+                base64::engine::general_purpose::STANDARD
+                    .decode(entry)
+                    .map_err(|e| eyre::eyre!("Invalid keystore entry {}: {}", index, e))
+                    .and_then(|bytes| {
+                        SuiKeyPair::from_bytes(&bytes)
+                            .map_err(|e| eyre::eyre!("Invalid keystore entry {}: {}", index, e))
+                    })
+            })
+            .collect()
+    }
+
+    /// Write keys to a `sui.keystore` file in the format read by `load_keystore`
+    pub fn save_keystore<P: AsRef<Path>>(path: P, keys: &[SuiKeyPair]) -> Result<(), eyre::Report> {
+        let entries: Vec<String> = keys
+            .iter()
+            .map(|key| base64::engine::general_purpose::STANDARD.encode(key.to_bytes()))
+            .collect();
+        std::fs::write(path, serde_json::to_string_pretty(&entries)?)?;
+        Ok(())
     }
 }
 
+impl fmt::Debug for SuiKeyPair {
+    // Never print the secret bytes; identify the key by its public fingerprint instead
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let scheme = match self {
+            SuiKeyPair::Ed25519(_) => "Ed25519",
+            SuiKeyPair::Secp256k1(_) => "Secp256k1",
+            SuiKeyPair::Secp256r1(_) => "Secp256r1",
+        };
+        match self.try_public() {
+            Ok(public_key) => write!(
+                f,
+                "SuiKeyPair::{}(pub: 0x{})",
+                scheme,
+                hex::encode(public_key.fingerprint())
+            ),
+            Err(_) => write!(f, "SuiKeyPair::{}(invalid)", scheme),
+        }
+    }
+}
+
+/// SLIP-10 marks hardened child indices, the only kind Ed25519 supports, with this bit
+#[cfg(feature = "mnemonic")]
+const SLIP10_HARDENED_OFFSET: u32 = 0x8000_0000;
+
+/// HMAC-SHA512 as used by SLIP-10: the first half is the key, the second the chain code
+#[cfg(feature = "mnemonic")]
+fn slip10_hmac(key: &[u8], data: &[u8]) -> [u8; 64] {
+    use hmac::Mac;
+
+    let mut mac =
+        hmac::Hmac::<sha2::Sha512>::new_from_slice(key).expect("HMAC accepts keys of any size");
+    mac.update(data);
+    mac.finalize().into_bytes().into()
+}
+
+/// Copy a SEC1 compressed point into its fixed-size array
+fn compressed_point(bytes: &[u8]) -> [u8; 33] {
+    bytes
+        .try_into()
+        .expect("SEC1 compressed points are 33 bytes")
+}
+
 /// Public key types
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum PublicKey {
@@ -81,14 +296,79 @@ pub enum PublicKey {
     Secp256r1([u8; 33]),
 }
 
+impl From<&SuiKeyPair> for PublicKey {
+    fn from(keypair: &SuiKeyPair) -> Self {
+        keypair.public()
+    }
+}
+
 impl PublicKey {
     pub fn flag(&self) -> u8 {
+        self.scheme().flag()
+    }
+
+    pub fn scheme(&self) -> SignatureScheme {
+        match self {
+            PublicKey::Ed25519(_) => SignatureScheme::ED25519,
+            PublicKey::Secp256k1(_) => SignatureScheme::Secp256k1,
+            PublicKey::Secp256r1(_) => SignatureScheme::Secp256r1,
+        }
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
         match self {
-            PublicKey::Ed25519(_) => SignatureScheme::ED25519.flag(),
-            PublicKey::Secp256k1(_) => SignatureScheme::Secp256k1.flag(),
-            PublicKey::Secp256r1(_) => SignatureScheme::Secp256r1.flag(),
+            PublicKey::Ed25519(bytes) => bytes,
+            PublicKey::Secp256k1(bytes) => bytes,
+            PublicKey::Secp256r1(bytes) => bytes,
         }
     }
+
+    /// Whether `signature` is a valid signature of `digest` under this key, signed as by
+    /// `SuiKeyPair::sign_digest`
+    fn verify_digest(&self, digest: &[u8; 32], signature: &BasicSignature) -> bool {
+        use k256::ecdsa::signature::Verifier;
+
+        let bytes = signature.signature_bytes.as_slice();
+        match self {
+            PublicKey::Ed25519(key) => {
+                let (Ok(key), Ok(signature)) = (
+                    ed25519_dalek::VerifyingKey::from_bytes(key),
+                    ed25519_dalek::Signature::from_slice(bytes),
+                ) else {
+                    return false;
+                };
+                key.verify_strict(digest, &signature).is_ok()
+            }
+            PublicKey::Secp256k1(key) => {
+                let (Ok(key), Ok(signature)) = (
+                    k256::ecdsa::VerifyingKey::from_sec1_bytes(key),
+                    k256::ecdsa::Signature::from_slice(bytes),
+                ) else {
+                    return false;
+                };
+                key.verify(digest, &signature).is_ok()
+            }
+            PublicKey::Secp256r1(key) => {
+                let (Ok(key), Ok(signature)) = (
+                    p256::ecdsa::VerifyingKey::from_sec1_bytes(key),
+                    p256::ecdsa::Signature::from_slice(bytes),
+                ) else {
+                    return false;
+                };
+                signature.normalize_s().is_none() && key.verify(digest, &signature).is_ok()
+            }
+        }
+    }
+
+    /// Short identifier for logs: the first 8 bytes of Blake2b-256 over the flagged key
+    pub fn fingerprint(&self) -> [u8; 8] {
+        let mut hasher = DefaultHash::new();
+        hasher.update([self.flag()]);
+        hasher.update(self.as_bytes());
+        let mut fingerprint = [0u8; 8];
+        fingerprint.copy_from_slice(&hasher.finalize()[..8]);
+        fingerprint
+    }
 }
 
 /// Signature schemes
@@ -135,22 +415,52 @@ pub struct BasicSignature {
 }
 
 impl Signature for BasicSignature {
-    fn verify(&self, _msg: &[u8], _pk: &PublicKey) -> bool {
-        // Simplified verification for minimal implementation
-        true
+    /// Check the signature over the Blake2b-256 digest of `msg` against `pk`
+    fn verify(&self, msg: &[u8], pk: &PublicKey) -> bool {
+        self.scheme == pk.scheme() && pk.verify_digest(&DefaultHash::digest(msg).into(), self)
     }
 
-    fn new_secure<T>(_msg: &T, _keypair: &SuiKeyPair) -> Self 
+    /// Sign the Blake2b-256 digest of the BCS encoding of `msg`.
+    ///
+    /// Panics under the same conditions as `SuiKeyPair::public`.
+    fn new_secure<T>(msg: &T, keypair: &SuiKeyPair) -> Self 
     where 
         T: serde::Serialize,
         Self: Sized,
     {
-        Self {
-            scheme: SignatureScheme::ED25519,
-            signature_bytes: vec![0u8; 64], // Placeholder signature
-        }
+        let bytes = bcs::to_bytes(msg).expect("Message serialization should not fail");
+        keypair
+            .sign_digest(&DefaultHash::digest(bytes).into())
+            .expect("SuiKeyPair should hold a valid secret key")
     }
 }
 
 // Re-export BasicSignature as SuiSignature for convenience
 pub type SuiSignature = BasicSignature;
+
+/// Intent payload of a proof of possession: the public key bytes followed by the address
+fn proof_of_possession_message(pk: &PublicKey, address: &SuiAddress) -> Vec<u8> {
+    let mut msg = pk.as_bytes().to_vec();
+    msg.extend_from_slice(address.inner());
+    msg
+}
+
+/// Sign a proof of possession of `keypair` for `address`
+pub fn generate_proof_of_possession(keypair: &SuiKeyPair, address: &SuiAddress) -> BasicSignature {
+    let msg = proof_of_possession_message(&keypair.public(), address);
+    let intent_msg = IntentMessage::new(Intent::sui_app(IntentScope::ProofOfPossession), msg);
+    BasicSignature::new_secure(&intent_msg, keypair)
+}
+
+/// Verify a proof of possession of `pk` for `address`
+pub fn verify_proof_of_possession(
+    sig: &BasicSignature,
+    pk: &PublicKey,
+    address: &SuiAddress,
+) -> bool {
+    let msg = proof_of_possession_message(pk, address);
+    sig.verify(
+        &intent_signing_bytes(IntentScope::ProofOfPossession, &msg),
+        pk,
+    )
+}
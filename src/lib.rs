@@ -2,16 +2,21 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub mod base_types;
+pub mod committee;
 pub mod crypto;
+pub mod limits;
 pub mod message_envelope;
 pub mod transaction;
+#[cfg(feature = "verbose-json")]
+pub mod verbose_json;
 
 pub use base_types::SuiAddress;
-pub use crypto::{EmptySignInfo, SuiKeyPair, SuiSignature, Signature};
+pub use committee::{Committee, CommitteeDiff};
+pub use crypto::{AuthInfo, EmptySignInfo, SuiKeyPair, SuiSignature, Signature};
 pub use message_envelope::Envelope;
 pub use transaction::{
     SenderSignedData, Transaction, TransactionData, TransactionKind, ProgrammableTransaction,
     CallArg, Command, MoveCall, ObjectRef, ObjectID, SequenceNumber, ObjectDigest, Digest,
-    GasData, TransactionExpiration, ObjectArg, TypeTag, StructTag, Intent, IntentMessage,
-    IntentScope, SenderSignedTransaction
+    GasData, TransactionExpiration, ObjectArg, TypeTag, StructTag, Intent, IntentMessage, AppId,
+    IntentScope, SenderSignedTransaction, TransactionError
 };
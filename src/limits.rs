@@ -0,0 +1,50 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+/// Maximum BCS size of a transaction's data in bytes
+pub const MAX_TX_SIZE_BYTES: usize = 128 * 1024;
+
+/// Maximum size of a single pure argument in bytes
+pub const MAX_PURE_ARGUMENT_SIZE: usize = 16 * 1024;
+
+/// Maximum number of commands in a programmable transaction
+pub const MAX_PROGRAMMABLE_TX_COMMANDS: usize = 1024;
+
+/// Maximum nesting depth of a type argument
+pub const MAX_TYPE_ARGUMENT_DEPTH: usize = 16;
+
+/// Maximum number of coins in a gas payment
+pub const MAX_GAS_PAYMENT_OBJECTS: usize = 256;
+
+/// Minimum gas budget in MIST
+pub const MIN_GAS_BUDGET: u64 = 1_000_000;
+
+/// Maximum gas budget in MIST (50 SUI)
+pub const MAX_GAS_BUDGET: u64 = 50_000_000_000;
+
+/// Limits applied by local validation. `Default` uses the constants above; networks with
+/// different settings override individual fields.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProtocolLimits {
+    pub max_tx_size_bytes: usize,
+    pub max_pure_argument_size: usize,
+    pub max_programmable_tx_commands: usize,
+    pub max_type_argument_depth: usize,
+    pub max_gas_payment_objects: usize,
+    pub min_gas_budget: u64,
+    pub max_gas_budget: u64,
+}
+
+impl Default for ProtocolLimits {
+    fn default() -> Self {
+        Self {
+            max_tx_size_bytes: MAX_TX_SIZE_BYTES,
+            max_pure_argument_size: MAX_PURE_ARGUMENT_SIZE,
+            max_programmable_tx_commands: MAX_PROGRAMMABLE_TX_COMMANDS,
+            max_type_argument_depth: MAX_TYPE_ARGUMENT_DEPTH,
+            max_gas_payment_objects: MAX_GAS_PAYMENT_OBJECTS,
+            min_gas_budget: MIN_GAS_BUDGET,
+            max_gas_budget: MAX_GAS_BUDGET,
+        }
+    }
+}
@@ -1,6 +1,7 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::crypto::AuthInfo;
 use serde::{Deserialize, Serialize};
 use std::fmt::{Debug, Display};
 
@@ -45,6 +46,30 @@ impl<T: Message + PartialEq + Eq, S: PartialEq + Eq> Envelope<T, S> {
     pub fn auth_signature(&self) -> &S {
         &self.auth_signature
     }
+
+    /// Transform the wrapped message, keeping the authentication info
+    pub fn map_data<U, F>(self, f: F) -> Envelope<U, S>
+    where
+        U: Message + PartialEq + Eq,
+        F: FnOnce(T) -> U,
+    {
+        Envelope {
+            data: f(self.data),
+            auth_signature: self.auth_signature,
+        }
+    }
+}
+
+impl<T: Message + PartialEq + Eq, S: AuthInfo + PartialEq + Eq> Envelope<T, S> {
+    /// Whether the envelope carries any signature
+    pub fn is_signed(&self) -> bool {
+        self.auth_signature.is_signed()
+    }
+
+    /// Number of signatures the envelope carries
+    pub fn signature_count(&self) -> usize {
+        self.auth_signature.signature_count()
+    }
 }
 
 impl<T: Message + Display + PartialEq + Eq, S: PartialEq + Eq> Display for Envelope<T, S> {
@@ -1,14 +1,20 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::base_types::SuiAddress;
-use crate::crypto::{EmptySignInfo, SuiSignature};
+use crate::base_types::{invalid_length, SuiAddress, SUI_FRAMEWORK_ADDRESS};
+use crate::crypto::{DefaultHash, PublicKey, SuiSignature};
+use crate::limits::{ProtocolLimits, MAX_GAS_BUDGET, MIN_GAS_BUDGET};
 use crate::message_envelope::{Envelope, Message};
+use blake2::Digest as _;
 use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
 use std::fmt;
+use std::str::FromStr;
+use std::sync::Arc;
 
 /// Simple digest type for minimal implementation
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[repr(transparent)]
 pub struct Digest([u8; 32]);
 
 impl Digest {
@@ -16,6 +22,10 @@ impl Digest {
         Self(bytes)
     }
 
+    pub fn inner(&self) -> &[u8; 32] {
+        &self.0
+    }
+
     /// Create a new digest from bytes
     pub fn from_bytes(bytes: &[u8]) -> Self {
         let mut digest = [0u8; 32];
@@ -23,13 +33,69 @@ impl Digest {
         digest[..len].copy_from_slice(&bytes[..len]);
         Self(digest)
     }
+
+    /// Parse a digest given either in Base58 or as `0x`-prefixed hex
+    pub fn parse_any(s: &str) -> Result<Self, anyhow::Error> {
+        let mut digest = [0u8; 32];
+        if let Some(hex_str) = s.strip_prefix("0x") {
+            if hex_str.len() != 64 || !hex_str.bytes().all(|b| b.is_ascii_hexdigit()) {
+                return Err(anyhow::anyhow!(
+                    "Invalid hex digest {}: expected 64 hex digits",
+                    s
+                ));
+            }
+            hex::decode_to_slice(hex_str, &mut digest)?;
+            return Ok(Self(digest));
+        }
+        let bytes = bs58::decode(s)
+            .into_vec()
+            .map_err(|e| anyhow::anyhow!("Invalid Base58 digest {}: {}", s, e))?;
+        if bytes.len() != 32 {
+            return Err(invalid_length("Base58 digest", 32, bytes.len()));
+        }
+        digest.copy_from_slice(&bytes);
+        Ok(Self(digest))
+    }
+
+    /// First `SHORT_ID_LEN` characters of the Base58 form, for support tickets and UIs
+    pub fn short_id(&self) -> String {
+        self.to_string().chars().take(SHORT_ID_LEN).collect()
+    }
+
+    /// Whether `short` is this digest's `short_id`
+    pub fn matches_short_id(&self, short: &str) -> bool {
+        self.short_id() == short
+    }
+
+    /// Deterministic stand-in for the effects digest of this transaction digest,
+    /// hashing it with the execution status code and the ids of created objects
+    pub fn derive_effects_digest(&self, status_code: u8, created: &[ObjectID]) -> Digest {
+        let mut hasher = DefaultHash::new();
+        hasher.update(self.0);
+        hasher.update([status_code]);
+        for id in created {
+            hasher.update(id.0.inner());
+        }
+        Self(hasher.finalize().into())
+    }
+}
+
+/// Length of `Digest::short_id` in Base58 characters
+pub const SHORT_ID_LEN: usize = 8;
+
+impl fmt::Display for Digest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&bs58::encode(self.0).into_string())
+    }
 }
 
 /// Transaction digest
 pub type TransactionDigest = Digest;
 
 /// Object reference (simplified)
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+///
+/// Ordered by `id`, then `version`, then `digest`, following field declaration order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub struct ObjectRef {
     pub id: ObjectID,
     pub version: SequenceNumber,
@@ -37,14 +103,42 @@ pub struct ObjectRef {
 }
 
 impl ObjectRef {
+    /// Length of the fixed-width encoding: id (32) + version (8) + digest (32)
+    pub const FIXED_BYTES_LEN: usize = 72;
+
     /// Create a new object reference
     pub fn new(id: ObjectID, version: SequenceNumber, digest: ObjectDigest) -> Self {
         Self { id, version, digest }
     }
+
+    /// Encode as a fixed 72-byte record: id, big-endian version, then digest
+    pub fn to_fixed_bytes(&self) -> [u8; Self::FIXED_BYTES_LEN] {
+        let mut bytes = [0u8; Self::FIXED_BYTES_LEN];
+        bytes[..32].copy_from_slice(self.id.0.inner());
+        bytes[32..40].copy_from_slice(&self.version.0.to_be_bytes());
+        bytes[40..].copy_from_slice(&self.digest.0 .0);
+        bytes
+    }
+
+    /// Decode a record produced by `to_fixed_bytes`
+    pub fn from_fixed_bytes(bytes: [u8; Self::FIXED_BYTES_LEN]) -> Self {
+        let mut id = [0u8; 32];
+        id.copy_from_slice(&bytes[..32]);
+        let mut version = [0u8; 8];
+        version.copy_from_slice(&bytes[32..40]);
+        let mut digest = [0u8; 32];
+        digest.copy_from_slice(&bytes[40..]);
+        Self {
+            id: ObjectID(SuiAddress::new(id)),
+            version: SequenceNumber(u64::from_be_bytes(version)),
+            digest: ObjectDigest(Digest(digest)),
+        }
+    }
 }
 
 /// Object ID (simplified)
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[repr(transparent)]
 pub struct ObjectID(SuiAddress);
 
 impl ObjectID {
@@ -54,27 +148,58 @@ impl ObjectID {
     pub fn new(address: SuiAddress) -> Self {
         Self(address)
     }
+
+    /// Derive the id of the `creation_num`-th object created by a transaction.
+    ///
+    /// Matches the runtime's allocation for created objects: Blake2b-256 over the
+    /// regular-object-id hashing scope byte, the transaction digest and the creation
+    /// index in little-endian.
+    pub fn derive_from(tx_digest: &TransactionDigest, creation_num: u64) -> ObjectID {
+        let mut hasher = DefaultHash::new();
+        hasher.update([REGULAR_OBJECT_ID_HASHING_SCOPE]);
+        hasher.update(tx_digest.0);
+        hasher.update(creation_num.to_le_bytes());
+        Self(SuiAddress::new(hasher.finalize().into()))
+    }
 }
 
-/// Sequence number (simplified)
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
-pub struct SequenceNumber(u64);
+impl fmt::Display for ObjectID {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
 
-impl Default for SequenceNumber {
-    fn default() -> Self {
-        Self(0)
+impl FromStr for ObjectID {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self(SuiAddress::from_str(s)?))
     }
 }
 
+/// Hashing scope byte the runtime prepends when deriving ids for created objects
+const REGULAR_OBJECT_ID_HASHING_SCOPE: u8 = 0xf1;
+
+/// Sequence number (simplified)
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize,
+)]
+pub struct SequenceNumber(u64);
+
 impl SequenceNumber {
     /// Create a new sequence number
     pub fn new(value: u64) -> Self {
         Self(value)
     }
+
+    pub fn value(&self) -> u64 {
+        self.0
+    }
 }
 
 /// Object digest (simplified)
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[repr(transparent)]
 pub struct ObjectDigest(Digest);
 
 impl ObjectDigest {
@@ -84,8 +209,17 @@ impl ObjectDigest {
     pub fn new(digest: Digest) -> Self {
         Self(digest)
     }
+
+    pub fn inner(&self) -> &Digest {
+        &self.0
+    }
 }
 
+// Id and digest types are transparent wrappers over 32 bytes; keep it that way
+const _: () = assert!(std::mem::size_of::<Digest>() == 32);
+const _: () = assert!(std::mem::size_of::<ObjectID>() == 32);
+const _: () = assert!(std::mem::size_of::<ObjectDigest>() == 32);
+
 /// Transaction kind (simplified)
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum TransactionKind {
@@ -97,6 +231,43 @@ impl TransactionKind {
     pub fn new(pt: ProgrammableTransaction) -> Self {
         Self::ProgrammableTransaction(pt)
     }
+
+    /// Wrap a lone move call as a one-command programmable transaction
+    pub fn from_single_move_call(move_call: MoveCall) -> TransactionKind {
+        Self::ProgrammableTransaction(ProgrammableTransaction::new(
+            vec![],
+            vec![Command::MoveCall(move_call)],
+        ))
+    }
+
+    /// The move call of a transaction that consists of exactly one move call and no inputs
+    pub fn as_single_move_call(&self) -> Option<&MoveCall> {
+        match self {
+            TransactionKind::ProgrammableTransaction(pt) => match pt.commands.as_slice() {
+                [Command::MoveCall(move_call)] if pt.inputs.is_empty() => Some(move_call),
+                _ => None,
+            },
+        }
+    }
+
+    /// Static variant name, suitable as a metrics label
+    pub fn kind_name(&self) -> &'static str {
+        match self {
+            TransactionKind::ProgrammableTransaction(_) => "ProgrammableTransaction",
+        }
+    }
+
+    fn bcs_size_hint(&self) -> usize {
+        match self {
+            TransactionKind::ProgrammableTransaction(pt) => MAX_BCS_PREFIX_LEN + pt.bcs_size_hint(),
+        }
+    }
+}
+
+impl From<MoveCall> for TransactionKind {
+    fn from(move_call: MoveCall) -> Self {
+        Self::from_single_move_call(move_call)
+    }
 }
 
 /// Programmable transaction (simplified)
@@ -111,13 +282,211 @@ impl ProgrammableTransaction {
     pub fn new(inputs: Vec<CallArg>, commands: Vec<Command>) -> Self {
         Self { inputs, commands }
     }
+
+    fn bcs_size_hint(&self) -> usize {
+        let inputs: usize = self.inputs.iter().map(CallArg::bcs_size_hint).sum();
+        let commands: usize = self.commands.iter().map(Command::bcs_size_hint).sum();
+        MAX_BCS_PREFIX_LEN + inputs + MAX_BCS_PREFIX_LEN + commands
+    }
+
+    /// Iterate over every call argument, both top-level inputs and those inlined in commands
+    fn call_args(&self) -> impl Iterator<Item = &CallArg> {
+        self.inputs
+            .iter()
+            .chain(self.commands.iter().flat_map(|command| match command {
+                Command::MoveCall(move_call) => move_call.arguments.iter(),
+            }))
+    }
+
+    /// Mutable counterpart of `call_args`
+    fn call_args_mut(&mut self) -> impl Iterator<Item = &mut CallArg> {
+        self.inputs
+            .iter_mut()
+            .chain(self.commands.iter_mut().flat_map(|command| match command {
+                Command::MoveCall(move_call) => move_call.arguments.iter_mut(),
+            }))
+    }
+
+    /// Collect the type arguments of every move call in command order, optionally de-duplicated
+    pub fn type_arguments(&self, dedup: bool) -> Vec<TypeTag> {
+        let mut type_arguments: Vec<TypeTag> = Vec::new();
+        for command in &self.commands {
+            match command {
+                Command::MoveCall(move_call) => {
+                    for type_argument in &move_call.type_arguments {
+                        if !dedup || !type_arguments.contains(type_argument) {
+                            type_arguments.push(type_argument.clone());
+                        }
+                    }
+                }
+            }
+        }
+        type_arguments
+    }
+
+    /// The distinct `T`s of every `Coin<T>` in move-call type arguments, or `[SUI]` if none
+    pub fn primary_coin_types(&self) -> Vec<StructTag> {
+        let mut coin_types: Vec<StructTag> = Vec::new();
+        for type_argument in self.type_arguments(false) {
+            type_argument.visit_structs(&mut |struct_tag| {
+                if let Some(coin_type) = struct_tag.coin_type() {
+                    if !coin_types.contains(coin_type) {
+                        coin_types.push(coin_type.clone());
+                    }
+                }
+            });
+        }
+        if coin_types.is_empty() {
+            coin_types.push(StructTag::sui_type());
+        }
+        coin_types
+    }
+
+    /// Rough storage footprint of the objects this transaction touches.
+    ///
+    /// This is a UI heuristic, not an on-chain guarantee: every distinct owned or shared
+    /// object input counts as `ESTIMATED_STORAGE_UNITS_PER_OBJECT`.
+    pub fn estimated_storage_units(&self) -> u64 {
+        let objects: HashSet<ObjectID> = self
+            .call_args()
+            .filter_map(|arg| match arg {
+                CallArg::Object(ObjectArg::ImmOrOwned(object_ref)) => Some(object_ref.id),
+                CallArg::Object(ObjectArg::Shared { id, .. }) => Some(*id),
+                CallArg::UnresolvedObject(id) => Some(*id),
+                CallArg::Pure(_) => None,
+            })
+            .collect();
+        objects.len() as u64 * ESTIMATED_STORAGE_UNITS_PER_OBJECT
+    }
+
+    /// Check that every use of a shared object agrees on its mutability and initial version
+    pub fn validate_shared_mutability(&self) -> Result<(), TransactionError> {
+        let mut seen: HashMap<ObjectID, (SequenceNumber, bool)> = HashMap::new();
+        for arg in self.call_args() {
+            if let CallArg::Object(ObjectArg::Shared {
+                id,
+                initial_shared_version,
+                mutable,
+            }) = arg
+            {
+                let (version, is_mutable) = *seen
+                    .entry(*id)
+                    .or_insert((*initial_shared_version, *mutable));
+                if version != *initial_shared_version {
+                    return Err(TransactionError::InconsistentSharedVersion(*id));
+                }
+                if is_mutable != *mutable {
+                    return Err(TransactionError::InconsistentSharedMutability(*id));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// The first move call, used to classify a transaction by its dominant call
+    pub fn primary_move_call(&self) -> Option<&MoveCall> {
+        // Every command is a move call here, so the first command is the primary call
+        self.commands.first().map(|command| match command {
+            Command::MoveCall(move_call) => move_call,
+        })
+    }
+
+    /// Fill in every `CallArg::UnresolvedObject` with the reference `resolver` returns.
+    ///
+    /// Resolves as many placeholders as possible, then errors listing any the resolver
+    /// could not supply or answered with a different object's reference.
+    pub fn resolve_objects<F>(&mut self, mut resolver: F) -> Result<(), anyhow::Error>
+    where
+        F: FnMut(ObjectID) -> Option<ObjectRef>,
+    {
+        let mut unresolved = Vec::new();
+        for arg in self.call_args_mut() {
+            if let CallArg::UnresolvedObject(id) = *arg {
+                match resolver(id) {
+                    Some(object_ref) if object_ref.id == id => {
+                        *arg = CallArg::Object(ObjectArg::ImmOrOwned(object_ref));
+                    }
+                    _ => unresolved.push(id.to_string()),
+                }
+            }
+        }
+        if unresolved.is_empty() {
+            Ok(())
+        } else {
+            Err(anyhow::anyhow!(
+                "Unresolved objects: {}",
+                unresolved.join(", ")
+            ))
+        }
+    }
+
+    /// Number of commands of each kind, keyed by `Command::kind_name`
+    pub fn command_kind_counts(&self) -> HashMap<&'static str, usize> {
+        let mut counts = HashMap::new();
+        for command in &self.commands {
+            *counts.entry(command.kind_name()).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    /// Whether any move call targets `0x2::transfer::share_object` or `public_share_object`.
+    ///
+    /// This is a name-based heuristic: it flags candidates for inspection and misses shared
+    /// objects created inside other packages' functions.
+    pub fn may_create_shared_objects(&self) -> bool {
+        self.commands.iter().any(|command| match command {
+            Command::MoveCall(move_call) => {
+                move_call.package.0 == SUI_FRAMEWORK_ADDRESS
+                    && move_call.module == "transfer"
+                    && matches!(
+                        move_call.function.as_str(),
+                        "share_object" | "public_share_object"
+                    )
+            }
+        })
+    }
+
+    /// Point every move call and struct type at package `from` to package `to` instead
+    pub fn remap_package(&mut self, from: ObjectID, to: ObjectID) {
+        for command in &mut self.commands {
+            match command {
+                Command::MoveCall(move_call) => {
+                    if move_call.package == from {
+                        move_call.package = to;
+                    }
+                    for type_argument in &mut move_call.type_arguments {
+                        type_argument.visit_structs_mut(&mut |struct_tag| {
+                            if struct_tag.address == from.0 {
+                                struct_tag.address = to.0;
+                            }
+                        });
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl From<Vec<Command>> for ProgrammableTransaction {
+    fn from(commands: Vec<Command>) -> Self {
+        Self::new(vec![], commands)
+    }
 }
 
+/// Storage units assumed per object by `ProgrammableTransaction::estimated_storage_units`
+pub const ESTIMATED_STORAGE_UNITS_PER_OBJECT: u64 = 100;
+
+/// Computation units assumed per command by `TransactionData::suggested_budget_range`
+pub const ESTIMATED_COMPUTATION_UNITS_PER_COMMAND: u64 = 1_000;
+
 /// Call argument (simplified)
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum CallArg {
     Pure(Vec<u8>),
     Object(ObjectArg),
+    /// Owned object known only by id, to be filled in by
+    /// `ProgrammableTransaction::resolve_objects` before signing
+    UnresolvedObject(ObjectID),
 }
 
 impl CallArg {
@@ -130,13 +499,138 @@ impl CallArg {
     pub fn new_object(object_arg: ObjectArg) -> Self {
         Self::Object(object_arg)
     }
+
+    /// Create a placeholder for an owned object whose version and digest are not yet known
+    pub fn unresolved_object(id: ObjectID) -> Self {
+        Self::UnresolvedObject(id)
+    }
+
+    fn bcs_size_hint(&self) -> usize {
+        MAX_BCS_PREFIX_LEN
+            + match self {
+                CallArg::Pure(bytes) => MAX_BCS_PREFIX_LEN + bytes.len(),
+                CallArg::Object(object_arg) => object_arg.bcs_size_hint(),
+                CallArg::UnresolvedObject(_) => 32,
+            }
+    }
+
+    /// Check that a pure argument's bytes decode exactly as the given primitive type
+    pub fn validate_pure_as(&self, tag: &TypeTag) -> Result<(), anyhow::Error> {
+        let CallArg::Pure(bytes) = self else {
+            return Err(anyhow::anyhow!("Expected a pure argument"));
+        };
+        // bcs::from_bytes rejects both short input and trailing bytes
+        match tag {
+            TypeTag::Bool => bcs::from_bytes::<bool>(bytes).map(|_| ()),
+            TypeTag::U8 => bcs::from_bytes::<u8>(bytes).map(|_| ()),
+            TypeTag::U64 => bcs::from_bytes::<u64>(bytes).map(|_| ()),
+            TypeTag::U128 => bcs::from_bytes::<u128>(bytes).map(|_| ()),
+            TypeTag::Address => bcs::from_bytes::<SuiAddress>(bytes).map(|_| ()),
+            TypeTag::Vector(inner) if **inner == TypeTag::U8 => {
+                bcs::from_bytes::<Vec<u8>>(bytes).map(|_| ())
+            }
+            _ => return Err(anyhow::anyhow!("Unsupported pure type {:?}", tag)),
+        }
+        .map_err(|e| anyhow::anyhow!("Pure argument does not decode as {:?}: {}", tag, e))
+    }
+
+    /// Readable JSON form used by `TransactionData::to_display_json`
+    fn to_display_json(&self) -> serde_json::Value {
+        match self {
+            CallArg::Pure(bytes) => {
+                serde_json::json!({ "pure": format!("0x{}", hex::encode(bytes)) })
+            }
+            CallArg::Object(ObjectArg::ImmOrOwned(object_ref)) => serde_json::json!({
+                "object": object_ref.id.to_string(),
+                "version": object_ref.version.0,
+            }),
+            CallArg::Object(ObjectArg::Shared {
+                id,
+                initial_shared_version,
+                mutable,
+            }) => serde_json::json!({
+                "sharedObject": id.to_string(),
+                "initialSharedVersion": initial_shared_version.0,
+                "mutable": mutable,
+            }),
+            CallArg::UnresolvedObject(id) => {
+                serde_json::json!({ "unresolvedObject": id.to_string() })
+            }
+        }
+    }
+
+    /// Create a pure argument for a Move `Option<T>`, encoded as a vector of length 0 or 1
+    pub fn pure_option<T: Serialize>(value: Option<&T>) -> Result<Self, bcs::Error> {
+        // BCS encodes `Option` exactly like Move's `vector<T>` of length 0 or 1
+        Ok(Self::Pure(bcs::to_bytes(&value)?))
+    }
+
+    /// Parse a `type:value` literal into a pure argument.
+    ///
+    /// Supports `u8`, `u64`, `u128`, `bool`, `address` (e.g. `address:0x2`), double-quoted
+    /// `string` and `vec<u8>` given as `[1,2,3]`.
+    pub fn from_typed_literal(s: &str) -> Result<Self, anyhow::Error> {
+        let (ty, value) = s
+            .split_once(':')
+            .ok_or_else(|| anyhow::anyhow!("Typed literal must be type:value: {}", s))?;
+        let bytes = match ty {
+            "u8" => bcs::to_bytes(&parse_literal_value::<u8>(ty, value)?),
+            "u64" => bcs::to_bytes(&parse_literal_value::<u64>(ty, value)?),
+            "u128" => bcs::to_bytes(&parse_literal_value::<u128>(ty, value)?),
+            "bool" => bcs::to_bytes(&parse_literal_value::<bool>(ty, value)?),
+            "address" => bcs::to_bytes(
+                &SuiAddress::from_hex_literal(value)
+                    .map_err(|e| anyhow::anyhow!("Invalid address value {}: {}", value, e))?,
+            ),
+            "string" => {
+                let string = value
+                    .strip_prefix('"')
+                    .and_then(|v| v.strip_suffix('"'))
+                    .ok_or_else(|| anyhow::anyhow!("String value must be quoted: {}", value))?;
+                bcs::to_bytes(string.as_bytes())
+            }
+            "vec<u8>" => {
+                let elements = value
+                    .strip_prefix('[')
+                    .and_then(|v| v.strip_suffix(']'))
+                    .ok_or_else(|| anyhow::anyhow!("vec<u8> value must be [..]: {}", value))?;
+                let elements = if elements.trim().is_empty() {
+                    vec![]
+                } else {
+                    elements
+                        .split(',')
+                        .map(|element| parse_literal_value::<u8>("vec<u8> element", element.trim()))
+                        .collect::<Result<Vec<u8>, _>>()?
+                };
+                bcs::to_bytes(&elements)
+            }
+            _ => return Err(anyhow::anyhow!("Unsupported literal type {}: {}", ty, s)),
+        };
+        Ok(Self::Pure(
+            bytes.expect("Pure value serialization should not fail"),
+        ))
+    }
+}
+
+/// Parse the value half of a typed literal, naming the type on failure
+fn parse_literal_value<T: FromStr>(ty: &str, value: &str) -> Result<T, anyhow::Error>
+where
+    T::Err: fmt::Display,
+{
+    value
+        .parse()
+        .map_err(|e| anyhow::anyhow!("Invalid {} value {}: {}", ty, value, e))
 }
 
 /// Object argument (simplified)
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum ObjectArg {
     ImmOrOwned(ObjectRef),
-    Shared { id: ObjectID },
+    Shared {
+        id: ObjectID,
+        initial_shared_version: SequenceNumber,
+        mutable: bool,
+    },
 }
 
 impl ObjectArg {
@@ -146,11 +640,59 @@ impl ObjectArg {
     }
 
     /// Create a new shared object argument
-    pub fn new_shared(id: ObjectID) -> Self {
-        Self::Shared { id }
+    pub fn new_shared(id: ObjectID, initial_shared_version: SequenceNumber, mutable: bool) -> Self {
+        Self::Shared {
+            id,
+            initial_shared_version,
+            mutable,
+        }
+    }
+
+    fn bcs_size_hint(&self) -> usize {
+        MAX_BCS_PREFIX_LEN
+            + match self {
+                ObjectArg::ImmOrOwned(_) => ObjectRef::FIXED_BYTES_LEN,
+                ObjectArg::Shared { .. } => 32 + 8 + 1,
+            }
+    }
+
+    /// Create a shared object argument from the id string, version and mutability reported by RPC
+    pub fn shared_from_parts(
+        id: &str,
+        initial_shared_version: u64,
+        mutable: bool,
+    ) -> Result<Self, anyhow::Error> {
+        Ok(Self::new_shared(
+            ObjectID::from_str(id)?,
+            SequenceNumber::new(initial_shared_version),
+            mutable,
+        ))
+    }
+
+    /// The object reference for owned arguments; shared arguments are referenced by id only
+    pub fn object_ref(&self) -> Option<&ObjectRef> {
+        match self {
+            ObjectArg::ImmOrOwned(object_ref) => Some(object_ref),
+            ObjectArg::Shared { .. } => None,
+        }
+    }
+}
+
+impl From<ObjectRef> for ObjectArg {
+    fn from(object_ref: ObjectRef) -> Self {
+        Self::ImmOrOwned(object_ref)
     }
 }
 
+/// How a transaction uses one of its object inputs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ObjectRole {
+    Gas,
+    Owned,
+    SharedMutable,
+    SharedReadOnly,
+}
+
 /// Command (simplified)
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Command {
@@ -162,6 +704,31 @@ impl Command {
     pub fn new_move_call(move_call: MoveCall) -> Self {
         Self::MoveCall(move_call)
     }
+
+    fn bcs_size_hint(&self) -> usize {
+        match self {
+            Command::MoveCall(move_call) => MAX_BCS_PREFIX_LEN + move_call.bcs_size_hint(),
+        }
+    }
+
+    /// Static variant name, suitable as a metrics label
+    pub fn kind_name(&self) -> &'static str {
+        match self {
+            Command::MoveCall(_) => "MoveCall",
+        }
+    }
+}
+
+impl fmt::Display for Command {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Command::MoveCall(move_call) => write!(
+                f,
+                "MoveCall({}::{}::{})",
+                move_call.package, move_call.module, move_call.function
+            ),
+        }
+    }
 }
 
 /// Move call (simplified)
@@ -208,6 +775,45 @@ impl MoveCall {
             arguments,
         }
     }
+
+    /// Create a new move call, parsing each type argument from its string form
+    pub fn with_type_args_str(
+        package: ObjectID,
+        module: &str,
+        function: &str,
+        type_args: &[&str],
+        arguments: Vec<CallArg>,
+    ) -> Result<Self, anyhow::Error> {
+        let type_arguments = type_args
+            .iter()
+            .enumerate()
+            .map(|(index, type_arg)| {
+                TypeTag::from_str(type_arg).map_err(|e| {
+                    anyhow::anyhow!("Invalid type argument {} ({}): {}", index, type_arg, e)
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self::new_with_str(
+            package,
+            module,
+            function,
+            type_arguments,
+            arguments,
+        ))
+    }
+
+    fn bcs_size_hint(&self) -> usize {
+        let type_arguments: usize = self.type_arguments.iter().map(TypeTag::bcs_size_hint).sum();
+        let arguments: usize = self.arguments.iter().map(CallArg::bcs_size_hint).sum();
+        32 + MAX_BCS_PREFIX_LEN
+            + self.module.len()
+            + MAX_BCS_PREFIX_LEN
+            + self.function.len()
+            + MAX_BCS_PREFIX_LEN
+            + type_arguments
+            + MAX_BCS_PREFIX_LEN
+            + arguments
+    }
 }
 
 /// Type tag (simplified)
@@ -257,6 +863,122 @@ impl TypeTag {
     pub fn new_struct(struct_tag: StructTag) -> Self {
         Self::Struct(struct_tag)
     }
+
+    /// Call `f` on every struct tag in this type, outermost first
+    fn visit_structs<F: FnMut(&StructTag)>(&self, f: &mut F) {
+        match self {
+            TypeTag::Vector(inner) => inner.visit_structs(f),
+            TypeTag::Struct(struct_tag) => {
+                f(struct_tag);
+                for type_param in &struct_tag.type_params {
+                    type_param.visit_structs(f);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Mutable counterpart of `visit_structs`
+    fn visit_structs_mut<F: FnMut(&mut StructTag)>(&mut self, f: &mut F) {
+        match self {
+            TypeTag::Vector(inner) => inner.visit_structs_mut(f),
+            TypeTag::Struct(struct_tag) => {
+                f(struct_tag);
+                for type_param in &mut struct_tag.type_params {
+                    type_param.visit_structs_mut(f);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Nesting depth of the type; primitives have depth 1
+    pub fn depth(&self) -> usize {
+        match self {
+            TypeTag::Vector(inner) => 1 + inner.depth(),
+            TypeTag::Struct(struct_tag) => {
+                1 + struct_tag
+                    .type_params
+                    .iter()
+                    .map(TypeTag::depth)
+                    .max()
+                    .unwrap_or(0)
+            }
+            _ => 1,
+        }
+    }
+
+    fn bcs_size_hint(&self) -> usize {
+        MAX_BCS_PREFIX_LEN
+            + match self {
+                TypeTag::Vector(inner) => inner.bcs_size_hint(),
+                TypeTag::Struct(struct_tag) => struct_tag.bcs_size_hint(),
+                _ => 0,
+            }
+    }
+}
+
+impl FromStr for TypeTag {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        match s {
+            "bool" => Ok(TypeTag::Bool),
+            "u8" => Ok(TypeTag::U8),
+            "u64" => Ok(TypeTag::U64),
+            "u128" => Ok(TypeTag::U128),
+            "address" => Ok(TypeTag::Address),
+            _ => match s
+                .strip_prefix("vector<")
+                .and_then(|rest| rest.strip_suffix('>'))
+            {
+                Some(inner) => Ok(TypeTag::new_vector(inner.parse()?)),
+                None => Ok(TypeTag::Struct(s.parse()?)),
+            },
+        }
+    }
+}
+
+impl fmt::Display for TypeTag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TypeTag::Bool => write!(f, "bool"),
+            TypeTag::U8 => write!(f, "u8"),
+            TypeTag::U64 => write!(f, "u64"),
+            TypeTag::U128 => write!(f, "u128"),
+            TypeTag::Address => write!(f, "address"),
+            TypeTag::Vector(inner) => write!(f, "vector<{}>", inner),
+            TypeTag::Struct(struct_tag) => write!(f, "{}", struct_tag),
+        }
+    }
+}
+
+/// Split a comma-separated type parameter list at the top nesting level
+fn split_type_params(s: &str) -> Result<Vec<&str>, anyhow::Error> {
+    let mut params = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (index, c) in s.char_indices() {
+        match c {
+            '<' => depth += 1,
+            '>' => {
+                depth = depth
+                    .checked_sub(1)
+                    .ok_or_else(|| anyhow::anyhow!("Unbalanced type parameters: {}", s))?
+            }
+            ',' if depth == 0 => {
+                params.push(&s[start..index]);
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+    if depth != 0 {
+        return Err(anyhow::anyhow!("Unbalanced type parameters: {}", s));
+    }
+    params.push(&s[start..]);
+    Ok(params)
 }
 
 /// Struct tag (simplified)
@@ -283,6 +1005,136 @@ impl StructTag {
             type_params,
         }
     }
+
+    /// The `0x2::sui::SUI` coin type
+    pub fn sui_type() -> Self {
+        Self::new(
+            SUI_FRAMEWORK_ADDRESS,
+            "sui".to_string(),
+            "SUI".to_string(),
+            vec![],
+        )
+    }
+
+    /// `T` if this tag is `0x2::coin::Coin<T>` for a struct `T`
+    fn coin_type(&self) -> Option<&StructTag> {
+        match self.type_params.as_slice() {
+            [TypeTag::Struct(coin_type)]
+                if self.address == SUI_FRAMEWORK_ADDRESS
+                    && self.module == "coin"
+                    && self.name == "Coin" =>
+            {
+                Some(coin_type)
+            }
+            _ => None,
+        }
+    }
+
+    /// Parse a coin type given either bare (`0x..::usdc::USDC`) or wrapped
+    /// (`0x2::coin::Coin<0x..::usdc::USDC>`), returning the inner coin type
+    pub fn parse_coin_type(s: &str) -> Result<StructTag, anyhow::Error> {
+        let struct_tag: StructTag = s.parse()?;
+        Ok(match struct_tag.coin_type() {
+            Some(coin_type) => coin_type.clone(),
+            None => struct_tag,
+        })
+    }
+
+    fn bcs_size_hint(&self) -> usize {
+        let type_params: usize = self.type_params.iter().map(TypeTag::bcs_size_hint).sum();
+        32 + MAX_BCS_PREFIX_LEN
+            + self.module.len()
+            + MAX_BCS_PREFIX_LEN
+            + self.name.len()
+            + MAX_BCS_PREFIX_LEN
+            + type_params
+    }
+}
+
+impl fmt::Display for StructTag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}::{}::{}", self.address, self.module, self.name)?;
+        if let Some((first, rest)) = self.type_params.split_first() {
+            write!(f, "<{}", first)?;
+            for type_param in rest {
+                write!(f, ", {}", type_param)?;
+            }
+            write!(f, ">")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for StructTag {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let (path, type_params) = match s.find('<') {
+            Some(start) => {
+                let params = s[start + 1..]
+                    .strip_suffix('>')
+                    .ok_or_else(|| anyhow::anyhow!("Unterminated type parameters: {}", s))?;
+                let type_params = split_type_params(params)?
+                    .into_iter()
+                    .map(TypeTag::from_str)
+                    .collect::<Result<Vec<_>, _>>()?;
+                (&s[..start], type_params)
+            }
+            None => (s, vec![]),
+        };
+        let parts: Vec<&str> = path.split("::").collect();
+        match parts.as_slice() {
+            [address, module, name] if !module.is_empty() && !name.is_empty() => Ok(Self {
+                address: SuiAddress::from_hex_literal(address)?,
+                module: module.to_string(),
+                name: name.to_string(),
+                type_params,
+            }),
+            _ => Err(anyhow::anyhow!("Invalid struct tag: {}", s)),
+        }
+    }
+}
+
+/// Interner mapping struct tags to compact ids, storing each distinct tag once
+#[derive(Debug, Default)]
+pub struct StructTagInterner {
+    ids: HashMap<Arc<StructTag>, u32>,
+    tags: Vec<Arc<StructTag>>,
+}
+
+impl StructTagInterner {
+    /// Create a new, empty interner
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Return the id for `tag`, assigning the next id if it has not been seen before
+    pub fn intern(&mut self, tag: StructTag) -> u32 {
+        if let Some(id) = self.ids.get(&tag) {
+            return *id;
+        }
+        let id = u32::try_from(self.tags.len()).expect("StructTagInterner exceeded u32 ids");
+        let tag = Arc::new(tag);
+        self.tags.push(tag.clone());
+        self.ids.insert(tag, id);
+        id
+    }
+
+    /// Look up the tag previously interned under `id`
+    pub fn resolve(&self, id: u32) -> Option<&StructTag> {
+        self.tags.get(id as usize).map(|tag| tag.as_ref())
+    }
+
+    /// Number of distinct tags interned
+    pub fn len(&self) -> usize {
+        self.tags.len()
+    }
+
+    /// Whether no tags have been interned
+    pub fn is_empty(&self) -> bool {
+        self.tags.is_empty()
+    }
 }
 
 /// Gas data (simplified)
@@ -304,8 +1156,140 @@ impl GasData {
             budget,
         }
     }
+
+    /// Raise the gas price by a priority tip, saturating instead of overflowing
+    pub fn with_tip(&mut self, tip: u64) {
+        self.price = self.price.saturating_add(tip);
+    }
+
+    /// The coin that survives gas smashing: all payment coins merge into the first
+    pub fn smashed_coin(&self) -> Option<ObjectRef> {
+        self.payment.first().copied()
+    }
+
+    /// The payment coins consumed by gas smashing
+    pub fn smashed_away(&self) -> &[ObjectRef] {
+        self.payment.get(1..).unwrap_or(&[])
+    }
+}
+
+/// Pick gas coins covering `budget`, largest balance first, using at most `max_coins`
+pub fn select_gas_coins(
+    available: &[(ObjectRef, u64)],
+    budget: u64,
+    max_coins: usize,
+) -> Result<Vec<ObjectRef>, GasError> {
+    let mut coins: Vec<&(ObjectRef, u64)> = available.iter().collect();
+    coins.sort_by_key(|(_, balance)| std::cmp::Reverse(*balance));
+
+    let mut selected = Vec::new();
+    let mut total = 0u64;
+    for (object_ref, balance) in coins {
+        if total >= budget {
+            break;
+        }
+        if selected.len() == max_coins {
+            return Err(GasError::TooManyCoins { budget, max_coins });
+        }
+        selected.push(*object_ref);
+        total = total.saturating_add(*balance);
+    }
+    if total < budget {
+        return Err(GasError::InsufficientBalance {
+            balance: total,
+            amount: budget,
+        });
+    }
+    Ok(selected)
+}
+
+/// Pairs of transactions `(earlier, later, coin)` in a batch that pay gas with the same coin
+pub fn detect_gas_conflicts(txs: &[TransactionData]) -> Vec<(usize, usize, ObjectID)> {
+    let mut users: HashMap<ObjectID, Vec<usize>> = HashMap::new();
+    let mut conflicts = Vec::new();
+    for (index, tx) in txs.iter().enumerate() {
+        for object_ref in &tx.gas_data.payment {
+            let earlier = users.entry(object_ref.id).or_default();
+            if earlier.last() == Some(&index) {
+                continue;
+            }
+            conflicts.extend(earlier.iter().map(|&i| (i, index, object_ref.id)));
+            earlier.push(index);
+        }
+    }
+    conflicts
+}
+
+/// Gas budget for a transaction whose dry run used the given gas, plus a safety margin.
+///
+/// The rebate offsets at most the storage cost; the margin is in basis points of the net
+/// cost, and the result is clamped to `[MIN_GAS_BUDGET, MAX_GAS_BUDGET]`.
+pub fn budget_from_gas_used(computation: u64, storage: u64, rebate: u64, margin_bps: u16) -> u64 {
+    let net = computation as u128 + (storage - rebate.min(storage)) as u128;
+    let budget = net + net * margin_bps as u128 / 10_000;
+    budget.clamp(MIN_GAS_BUDGET as u128, MAX_GAS_BUDGET as u128) as u64
+}
+
+/// Gas coin with a resolved balance, for simulating gas bookkeeping
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GasCoin {
+    pub object_ref: ObjectRef,
+    balance: u64,
+}
+
+impl GasCoin {
+    /// Create a new gas coin
+    pub fn new(object_ref: ObjectRef, balance: u64) -> Self {
+        Self {
+            object_ref,
+            balance,
+        }
+    }
+
+    /// Remaining balance in MIST
+    pub fn balance(&self) -> u64 {
+        self.balance
+    }
+
+    /// Deduct `amount` for a split, returning the amount split off
+    pub fn split_off(&mut self, amount: u64) -> Result<u64, GasError> {
+        self.balance = self
+            .balance
+            .checked_sub(amount)
+            .ok_or(GasError::InsufficientBalance {
+                balance: self.balance,
+                amount,
+            })?;
+        Ok(amount)
+    }
+}
+
+/// Failure while selecting, resolving or spending gas coins
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GasError {
+    InsufficientBalance { balance: u64, amount: u64 },
+    TooManyCoins { budget: u64, max_coins: usize },
+    MissingCoin(ObjectID),
+}
+
+impl fmt::Display for GasError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GasError::InsufficientBalance { balance, amount } => {
+                write!(f, "Insufficient gas coin balance: {} < {}", balance, amount)
+            }
+            GasError::TooManyCoins { budget, max_coins } => write!(
+                f,
+                "Cannot cover gas budget {} with at most {} coins",
+                budget, max_coins
+            ),
+            GasError::MissingCoin(id) => write!(f, "No balance known for gas coin {}", id),
+        }
+    }
 }
 
+impl std::error::Error for GasError {}
+
 /// Transaction expiration (simplified)
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum TransactionExpiration {
@@ -325,6 +1309,12 @@ impl TransactionExpiration {
     }
 }
 
+/// Upper bound on the bytes BCS spends on a ULEB128 length or enum variant index
+const MAX_BCS_PREFIX_LEN: usize = 5;
+
+/// Format version written by `TransactionData::to_versioned_bytes`
+pub const TRANSACTION_DATA_FORMAT_VERSION: u8 = 1;
+
 /// Transaction data (simplified)
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct TransactionData {
@@ -374,6 +1364,573 @@ impl TransactionData {
             expiration: TransactionExpiration::None,
         }
     }
+
+    /// Readable JSON summary for signing-confirmation screens.
+    ///
+    /// Keys appear in a fixed order (sender, gas, expiration, commands). Unlike the serde
+    /// derive, this favours readability over BCS fidelity and is not meant to be parsed back.
+    pub fn to_display_json(&self) -> serde_json::Value {
+        let TransactionKind::ProgrammableTransaction(pt) = &self.kind;
+        let expiration = match self.expiration {
+            TransactionExpiration::None => serde_json::Value::Null,
+            TransactionExpiration::Epoch(epoch) => serde_json::json!({ "epoch": epoch }),
+        };
+        let commands: Vec<serde_json::Value> = pt
+            .commands
+            .iter()
+            .map(|command| match command {
+                Command::MoveCall(move_call) => serde_json::json!({
+                    "moveCall": {
+                        "target": format!(
+                            "{}::{}::{}",
+                            move_call.package, move_call.module, move_call.function
+                        ),
+                        "typeArguments": move_call
+                            .type_arguments
+                            .iter()
+                            .map(|type_argument| type_argument.to_string())
+                            .collect::<Vec<_>>(),
+                        "arguments": move_call
+                            .arguments
+                            .iter()
+                            .map(CallArg::to_display_json)
+                            .collect::<Vec<_>>(),
+                    }
+                }),
+            })
+            .collect();
+        serde_json::json!({
+            "sender": self.sender.to_string(),
+            "gas": {
+                "budget": self.gas_data.budget,
+                "price": self.gas_data.price,
+                "coins": self
+                    .gas_data
+                    .payment
+                    .iter()
+                    .map(|object_ref| object_ref.id.to_string())
+                    .collect::<Vec<_>>(),
+            },
+            "expiration": expiration,
+            "commands": commands,
+        })
+    }
+
+    /// Compile a JSON recipe into a transaction.
+    ///
+    /// The recipe is one command or an array of them; only `moveCall` commands exist here:
+    /// `{ "moveCall": { "target": "0x2::m::f", "typeArgs": [..], "args": [..] } }`. Each arg
+    /// is a typed literal string (see `CallArg::from_typed_literal`), an owned object
+    /// `{ "object", "version", "digest" }`, a shared object
+    /// `{ "sharedObject", "initialSharedVersion", "mutable" }` or an unresolved owned object
+    /// `{ "unresolvedObject" }` for `ProgrammableTransaction::resolve_objects`.
+    pub fn from_recipe(
+        recipe: &serde_json::Value,
+        sender: SuiAddress,
+        gas: ObjectRef,
+        budget: u64,
+        price: u64,
+    ) -> Result<Self, anyhow::Error> {
+        let commands = match recipe {
+            serde_json::Value::Array(steps) => steps
+                .iter()
+                .map(command_from_recipe)
+                .collect::<Result<Vec<_>, _>>()?,
+            step => vec![command_from_recipe(step)?],
+        };
+        Ok(Self::new(
+            TransactionKind::ProgrammableTransaction(commands.into()),
+            sender,
+            gas,
+            budget,
+            price,
+        ))
+    }
+
+    /// Pair each gas payment coin with its balance from `balance_of`, in payment order.
+    ///
+    /// Errors on the first coin `balance_of` knows nothing about; the wire format is unchanged.
+    pub fn gas_coins<F>(&self, mut balance_of: F) -> Result<Vec<GasCoin>, GasError>
+    where
+        F: FnMut(&ObjectRef) -> Option<u64>,
+    {
+        self.gas_data
+            .payment
+            .iter()
+            .map(|object_ref| {
+                balance_of(object_ref)
+                    .map(|balance| GasCoin::new(*object_ref, balance))
+                    .ok_or(GasError::MissingCoin(object_ref.id))
+            })
+            .collect()
+    }
+
+    /// Approximate serialized size, never smaller than the actual BCS encoding
+    pub fn bcs_size_hint(&self) -> usize {
+        self.kind.bcs_size_hint()
+            + 32
+            + MAX_BCS_PREFIX_LEN
+            + self.gas_data.payment.len() * ObjectRef::FIXED_BYTES_LEN
+            + 32
+            + 8
+            + 8
+            + MAX_BCS_PREFIX_LEN
+            + 8
+    }
+
+    /// Serialize to BCS into a buffer pre-sized from `bcs_size_hint`
+    pub fn to_bcs_bytes(&self) -> Result<Vec<u8>, bcs::Error> {
+        let mut bytes = Vec::with_capacity(self.bcs_size_hint());
+        bcs::serialize_into(&mut bytes, self)?;
+        Ok(bytes)
+    }
+
+    /// Serialize to BCS prefixed with a one-byte format version
+    pub fn to_versioned_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(1 + self.bcs_size_hint());
+        bytes.push(TRANSACTION_DATA_FORMAT_VERSION);
+        bcs::serialize_into(&mut bytes, self)
+            .expect("TransactionData serialization should not fail");
+        bytes
+    }
+
+    /// Deserialize bytes produced by `to_versioned_bytes`, rejecting unknown format versions
+    pub fn from_versioned_bytes(bytes: &[u8]) -> Result<Self, anyhow::Error> {
+        let (version, payload) = bytes
+            .split_first()
+            .ok_or_else(|| anyhow::anyhow!("Missing format version byte"))?;
+        if *version != TRANSACTION_DATA_FORMAT_VERSION {
+            return Err(anyhow::anyhow!(
+                "Unknown TransactionData format version {}",
+                version
+            ));
+        }
+        Ok(bcs::from_bytes(payload)?)
+    }
+
+    /// Wrap in an intent message with the default Sui transaction-data intent
+    pub fn into_intent_message(self) -> IntentMessage<TransactionData> {
+        IntentMessage::new(Intent::sui_app(IntentScope::TransactionData), self)
+    }
+
+    /// Borrowing variant of `into_intent_message`
+    pub fn intent_message(&self) -> IntentMessage<&TransactionData> {
+        IntentMessage::new(Intent::sui_app(IntentScope::TransactionData), self)
+    }
+
+    /// Blake2b-256 digest of the intent message a signer signs for this transaction.
+    ///
+    /// Errors if any `CallArg::UnresolvedObject` placeholder is left, since the network
+    /// would reject a signature over it.
+    pub fn signing_digest(&self) -> Result<[u8; 32], TransactionError> {
+        self.ensure_resolved()?;
+        Ok(intent_signing_digest(IntentScope::TransactionData, self))
+    }
+
+    /// Error on the first `CallArg::UnresolvedObject` still awaiting `resolve_objects`
+    pub fn ensure_resolved(&self) -> Result<(), TransactionError> {
+        let TransactionKind::ProgrammableTransaction(pt) = &self.kind;
+        match pt.call_args().find_map(|arg| match arg {
+            CallArg::UnresolvedObject(id) => Some(*id),
+            _ => None,
+        }) {
+            Some(id) => Err(TransactionError::UnresolvedObject(id)),
+            None => Ok(()),
+        }
+    }
+
+    /// The gas price actually paid: the set price, but never below the reference price
+    pub fn effective_gas_price(&self, reference: u64) -> u64 {
+        self.gas_data.price.max(reference)
+    }
+
+    /// Gas budget bounds for a UI slider at `reference_price`.
+    ///
+    /// The minimum is the estimated computation and storage units times the price, raised to
+    /// `MIN_GAS_BUDGET`; the maximum is `MAX_GAS_BUDGET`.
+    pub fn suggested_budget_range(&self, reference_price: u64) -> (u64, u64) {
+        let TransactionKind::ProgrammableTransaction(pt) = &self.kind;
+        let units = (pt.commands.len() as u64)
+            .saturating_mul(ESTIMATED_COMPUTATION_UNITS_PER_COMMAND)
+            .saturating_add(pt.estimated_storage_units());
+        let min = units
+            .saturating_mul(reference_price)
+            .clamp(MIN_GAS_BUDGET, MAX_GAS_BUDGET);
+        (min, MAX_GAS_BUDGET)
+    }
+
+    /// Advance the version of every owned object input and gas coin by `by`, leaving shared
+    /// and pure inputs untouched
+    pub fn bump_object_versions(&mut self, by: u64) {
+        let TransactionKind::ProgrammableTransaction(pt) = &mut self.kind;
+        let owned_inputs = pt.call_args_mut().filter_map(|arg| match arg {
+            CallArg::Object(ObjectArg::ImmOrOwned(object_ref)) => Some(object_ref),
+            _ => None,
+        });
+        for object_ref in owned_inputs.chain(self.gas_data.payment.iter_mut()) {
+            object_ref.version = SequenceNumber(object_ref.version.0.saturating_add(by));
+        }
+    }
+
+    /// Whether both transactions do the same thing, ignoring gas data
+    pub fn same_intent(&self, other: &TransactionData) -> bool {
+        self.kind == other.kind
+            && self.sender == other.sender
+            && self.expiration == other.expiration
+    }
+
+    /// Replace the sender, moving gas ownership along unless the transaction is sponsored
+    pub fn with_sender(mut self, sender: SuiAddress) -> Self {
+        if self.gas_data.owner == self.sender {
+            self.gas_data.owner = sender;
+        }
+        self.sender = sender;
+        self
+    }
+
+    /// Owned object ids (inputs and gas coins) and shared object ids with their mutability
+    fn object_accesses(&self) -> (HashSet<ObjectID>, HashMap<ObjectID, bool>) {
+        let TransactionKind::ProgrammableTransaction(pt) = &self.kind;
+        let mut owned: HashSet<ObjectID> = self.gas_data.payment.iter().map(|r| r.id).collect();
+        let mut shared: HashMap<ObjectID, bool> = HashMap::new();
+        for arg in pt.call_args() {
+            match arg {
+                CallArg::Object(ObjectArg::ImmOrOwned(object_ref)) => {
+                    owned.insert(object_ref.id);
+                }
+                CallArg::UnresolvedObject(id) => {
+                    owned.insert(*id);
+                }
+                CallArg::Object(ObjectArg::Shared { id, mutable, .. }) => {
+                    *shared.entry(*id).or_insert(false) |= *mutable;
+                }
+                CallArg::Pure(_) => {}
+            }
+        }
+        (owned, shared)
+    }
+
+    /// Every object the transaction touches with its role: gas coins first, then object
+    /// arguments in input and command order
+    pub fn object_input_roles(&self) -> Vec<(ObjectID, ObjectRole)> {
+        let TransactionKind::ProgrammableTransaction(pt) = &self.kind;
+        let gas = self
+            .gas_data
+            .payment
+            .iter()
+            .map(|object_ref| (object_ref.id, ObjectRole::Gas));
+        let inputs = pt.call_args().filter_map(|arg| match arg {
+            CallArg::Object(ObjectArg::ImmOrOwned(object_ref)) => {
+                Some((object_ref.id, ObjectRole::Owned))
+            }
+            CallArg::UnresolvedObject(id) => Some((*id, ObjectRole::Owned)),
+            CallArg::Object(ObjectArg::Shared { id, mutable, .. }) => Some((
+                *id,
+                if *mutable {
+                    ObjectRole::SharedMutable
+                } else {
+                    ObjectRole::SharedReadOnly
+                },
+            )),
+            CallArg::Pure(_) => None,
+        });
+        gas.chain(inputs).collect()
+    }
+
+    /// Normalize the transaction so equivalent constructions encode to identical bytes.
+    ///
+    /// Sorts the gas payment after its first coin, which stays in place because smashing
+    /// merges the others into it. Inputs are left alone: commands take their arguments
+    /// inline rather than by input index, so a repeated input is not a redundant reference
+    /// and dropping it would change what the transaction declares. Type-tag addresses are
+    /// always stored at full length, so no other pass is needed. This changes the digest of
+    /// any transaction that was not already canonical.
+    pub fn canonicalize_full(&mut self) {
+        if let Some(rest) = self.gas_data.payment.get_mut(1..) {
+            rest.sort();
+        }
+    }
+
+    /// Check the transaction against a network's limits, returning every violation found
+    pub fn check_against(&self, limits: &ProtocolLimits) -> Result<(), Vec<TransactionError>> {
+        let mut errors = Vec::new();
+        let TransactionKind::ProgrammableTransaction(pt) = &self.kind;
+
+        if let Err(e) = pt.validate_shared_mutability() {
+            errors.push(e);
+        }
+
+        match self.to_bcs_bytes() {
+            Ok(bytes) if bytes.len() > limits.max_tx_size_bytes => {
+                errors.push(TransactionError::TooLarge {
+                    size: bytes.len(),
+                    max: limits.max_tx_size_bytes,
+                })
+            }
+            Ok(_) => {}
+            Err(e) => errors.push(TransactionError::Serialization(e.to_string())),
+        }
+        if pt.commands.len() > limits.max_programmable_tx_commands {
+            errors.push(TransactionError::TooManyCommands {
+                count: pt.commands.len(),
+                max: limits.max_programmable_tx_commands,
+            });
+        }
+        for arg in pt.call_args() {
+            match arg {
+                CallArg::Pure(bytes) if bytes.len() > limits.max_pure_argument_size => {
+                    errors.push(TransactionError::PureArgumentTooLarge {
+                        size: bytes.len(),
+                        max: limits.max_pure_argument_size,
+                    })
+                }
+                CallArg::UnresolvedObject(id) => {
+                    errors.push(TransactionError::UnresolvedObject(*id))
+                }
+                _ => {}
+            }
+        }
+        for type_argument in pt.type_arguments(false) {
+            if type_argument.depth() > limits.max_type_argument_depth {
+                errors.push(TransactionError::TypeArgumentTooDeep {
+                    type_argument,
+                    max: limits.max_type_argument_depth,
+                });
+            }
+        }
+
+        // Command arguments are inline, so an owned object passed to two commands, or a gas
+        // coin also passed as an argument, appears twice here
+        let mut owned_ids = HashSet::new();
+        let owned_args = pt.call_args().filter_map(|arg| match arg {
+            CallArg::Object(ObjectArg::ImmOrOwned(object_ref)) => Some(object_ref),
+            _ => None,
+        });
+        for object_ref in self.gas_data.payment.iter().chain(owned_args) {
+            if !owned_ids.insert(object_ref.id) {
+                errors.push(TransactionError::DuplicateObject(object_ref.id));
+            }
+        }
+
+        if self.gas_data.payment.is_empty() {
+            errors.push(TransactionError::EmptyGasPayment);
+        }
+        if self.gas_data.payment.len() > limits.max_gas_payment_objects {
+            errors.push(TransactionError::TooManyGasCoins {
+                count: self.gas_data.payment.len(),
+                max: limits.max_gas_payment_objects,
+            });
+        }
+        if self.gas_data.budget < limits.min_gas_budget
+            || self.gas_data.budget > limits.max_gas_budget
+        {
+            errors.push(TransactionError::GasBudgetOutOfRange {
+                budget: self.gas_data.budget,
+                min: limits.min_gas_budget,
+                max: limits.max_gas_budget,
+            });
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// The first move call of the transaction, if any
+    pub fn primary_move_call(&self) -> Option<&MoveCall> {
+        match &self.kind {
+            TransactionKind::ProgrammableTransaction(pt) => pt.primary_move_call(),
+        }
+    }
+
+    /// Field-level differences from `other` as `field: ours != theirs`, empty if equal
+    pub fn diff(&self, other: &TransactionData) -> Vec<String> {
+        let mut diffs = Vec::new();
+        let mut check = |field: &str, ours: String, theirs: String| {
+            if ours != theirs {
+                diffs.push(format!("{}: {} != {}", field, ours, theirs));
+            }
+        };
+        check("sender", self.sender.to_string(), other.sender.to_string());
+        check(
+            "gas_data.owner",
+            self.gas_data.owner.to_string(),
+            other.gas_data.owner.to_string(),
+        );
+        check(
+            "gas_data.price",
+            self.gas_data.price.to_string(),
+            other.gas_data.price.to_string(),
+        );
+        check(
+            "gas_data.budget",
+            self.gas_data.budget.to_string(),
+            other.gas_data.budget.to_string(),
+        );
+        check(
+            "gas_data.payment",
+            format!("{:?}", self.gas_data.payment),
+            format!("{:?}", other.gas_data.payment),
+        );
+        check(
+            "expiration",
+            format!("{:?}", self.expiration),
+            format!("{:?}", other.expiration),
+        );
+
+        let TransactionKind::ProgrammableTransaction(ours) = &self.kind;
+        let TransactionKind::ProgrammableTransaction(theirs) = &other.kind;
+        for i in 0..ours.inputs.len().max(theirs.inputs.len()) {
+            check(
+                &format!("inputs[{}]", i),
+                format!("{:?}", ours.inputs.get(i)),
+                format!("{:?}", theirs.inputs.get(i)),
+            );
+        }
+        for i in 0..ours.commands.len().max(theirs.commands.len()) {
+            check(
+                &format!("commands[{}]", i),
+                format!("{:?}", ours.commands.get(i)),
+                format!("{:?}", theirs.commands.get(i)),
+            );
+        }
+        diffs
+    }
+
+    /// Check that `expected_bcs` encodes this same transaction, reporting a field-level diff
+    pub fn assert_matches_bytes(&self, expected_bcs: &[u8]) -> Result<(), Vec<String>> {
+        let expected: TransactionData = bcs::from_bytes(expected_bcs)
+            .map_err(|e| vec![format!("Expected bytes are not a TransactionData: {}", e)])?;
+        let diffs = self.diff(&expected);
+        if diffs.is_empty() {
+            Ok(())
+        } else {
+            Err(diffs)
+        }
+    }
+
+    /// Replace the expiration, keeping every other field
+    pub fn with_expiration(mut self, expiration: TransactionExpiration) -> Self {
+        self.expiration = expiration;
+        self
+    }
+
+    /// Remove the expiration so the transaction never expires
+    pub fn clear_expiration(&mut self) {
+        self.expiration = TransactionExpiration::None;
+    }
+
+    /// The epoch after which this transaction can no longer execute, if any
+    pub fn expiration_epoch(&self) -> Option<u64> {
+        match self.expiration {
+            TransactionExpiration::None => None,
+            TransactionExpiration::Epoch(epoch) => Some(epoch),
+        }
+    }
+
+    /// Whether the transaction has expired once the network is at `current_epoch`
+    pub fn is_expired_at(&self, current_epoch: u64) -> bool {
+        self.expiration_epoch()
+            .is_some_and(|epoch| current_epoch > epoch)
+    }
+}
+
+/// Whether `a` and `b` cannot run in parallel: they share an owned object or gas coin, or
+/// touch the same shared object with at least one of them mutating it
+pub fn transactions_conflict(a: &TransactionData, b: &TransactionData) -> bool {
+    let (a_owned, a_shared) = a.object_accesses();
+    let (b_owned, b_shared) = b.object_accesses();
+    !a_owned.is_disjoint(&b_owned)
+        || a_shared.iter().any(|(id, a_mutable)| {
+            b_shared
+                .get(id)
+                .is_some_and(|b_mutable| *a_mutable || *b_mutable)
+        })
+}
+
+/// Parse one command of a `TransactionData::from_recipe` recipe
+fn command_from_recipe(step: &serde_json::Value) -> Result<Command, anyhow::Error> {
+    let (kind, move_call) = step
+        .as_object()
+        .filter(|step| step.len() == 1)
+        .and_then(|step| step.iter().next())
+        .ok_or_else(|| anyhow::anyhow!("Recipe command must be a single-key object"))?;
+    if kind != "moveCall" {
+        return Err(anyhow::anyhow!("Unsupported recipe command {}", kind));
+    }
+
+    let target = move_call["target"]
+        .as_str()
+        .ok_or_else(|| anyhow::anyhow!("moveCall target must be a string"))?;
+    let [package, module, function] = target.split("::").collect::<Vec<_>>()[..] else {
+        return Err(anyhow::anyhow!("Invalid moveCall target {}", target));
+    };
+    let type_args = match &move_call["typeArgs"] {
+        serde_json::Value::Null => vec![],
+        serde_json::Value::Array(type_args) => type_args
+            .iter()
+            .map(|type_arg| {
+                type_arg
+                    .as_str()
+                    .ok_or_else(|| anyhow::anyhow!("Type argument must be a string"))
+            })
+            .collect::<Result<Vec<_>, _>>()?,
+        _ => return Err(anyhow::anyhow!("moveCall typeArgs must be an array")),
+    };
+    let arguments = match &move_call["args"] {
+        serde_json::Value::Null => vec![],
+        serde_json::Value::Array(args) => args
+            .iter()
+            .map(call_arg_from_recipe)
+            .collect::<Result<Vec<_>, _>>()?,
+        _ => return Err(anyhow::anyhow!("moveCall args must be an array")),
+    };
+
+    Ok(Command::MoveCall(MoveCall::with_type_args_str(
+        ObjectID::new(SuiAddress::from_hex_literal(package)?),
+        module,
+        function,
+        &type_args,
+        arguments,
+    )?))
+}
+
+/// Parse one argument of a `TransactionData::from_recipe` move call
+fn call_arg_from_recipe(arg: &serde_json::Value) -> Result<CallArg, anyhow::Error> {
+    let version = |key: &str| {
+        arg[key]
+            .as_u64()
+            .map(SequenceNumber::new)
+            .ok_or_else(|| anyhow::anyhow!("Recipe object argument needs a numeric {}", key))
+    };
+    if let Some(literal) = arg.as_str() {
+        CallArg::from_typed_literal(literal)
+    } else if let Some(id) = arg["object"].as_str() {
+        let digest = arg["digest"]
+            .as_str()
+            .ok_or_else(|| anyhow::anyhow!("Recipe object argument needs a digest"))?;
+        Ok(CallArg::Object(ObjectArg::ImmOrOwned(ObjectRef::new(
+            ObjectID::new(SuiAddress::from_hex_literal(id)?),
+            version("version")?,
+            ObjectDigest::new(Digest::parse_any(digest)?),
+        ))))
+    } else if let Some(id) = arg["unresolvedObject"].as_str() {
+        Ok(CallArg::unresolved_object(ObjectID::new(
+            SuiAddress::from_hex_literal(id)?,
+        )))
+    } else if let Some(id) = arg["sharedObject"].as_str() {
+        let mutable = arg["mutable"]
+            .as_bool()
+            .ok_or_else(|| anyhow::anyhow!("Recipe shared object argument needs mutable"))?;
+        Ok(CallArg::Object(ObjectArg::new_shared(
+            ObjectID::new(SuiAddress::from_hex_literal(id)?),
+            version("initialSharedVersion")?,
+            mutable,
+        )))
+    } else {
+        Err(anyhow::anyhow!("Unsupported recipe argument {}", arg))
+    }
 }
 
 /// Sender signed transaction (simplified)
@@ -403,11 +1960,28 @@ impl<T> IntentMessage<T> {
     }
 }
 
+impl<T: Serialize> IntentMessage<T> {
+    /// BCS bytes that get signed: the intent followed by the value
+    pub fn signing_bytes(&self) -> Result<Vec<u8>, bcs::Error> {
+        bcs::to_bytes(self)
+    }
+
+    /// The intent bytes and the value bytes as separate buffers; they concatenate to
+    /// `signing_bytes`
+    pub fn parts(&self) -> Result<(Vec<u8>, Vec<u8>), bcs::Error> {
+        Ok((bcs::to_bytes(&self.intent)?, bcs::to_bytes(&self.value)?))
+    }
+}
+
 /// Intent (simplified)
+///
+/// Fields are declared in wire order, so BCS encodes the three bytes `scope, version,
+/// app_id` that Sui signers, including hardware wallets, expect.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct Intent {
-    pub version: u8,
     pub scope: IntentScope,
+    pub version: u8,
+    pub app_id: AppId,
 }
 
 /// Intent scope (simplified)
@@ -424,29 +1998,77 @@ impl IntentScope {
     }
 }
 
+/// Application an intent is addressed to
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum AppId {
+    Sui,
+    Narwhal,
+    Consensus,
+}
+
 impl Intent {
     /// Create a new intent with default values
     pub fn new(scope: IntentScope) -> Self {
         Self {
-            version: 0,
             scope,
+            version: 0,
+            app_id: AppId::Sui,
         }
     }
     
     /// Create a new intent with custom version
     pub fn with_version(version: u8, scope: IntentScope) -> Self {
-        Self { version, scope }
+        Self {
+            scope,
+            version,
+            app_id: AppId::Sui,
+        }
     }
 
     /// Create a sui app intent
     pub fn sui_app(scope: IntentScope) -> Self {
         Self {
-            version: 0,
             scope,
+            version: 0,
+            app_id: AppId::Sui,
         }
     }
 }
 
+/// BCS bytes to sign for `value` under the Sui intent with the given scope
+pub fn intent_signing_bytes<T: Serialize>(scope: IntentScope, value: &T) -> Vec<u8> {
+    IntentMessage::new(Intent::sui_app(scope), value)
+        .signing_bytes()
+        .expect("IntentMessage serialization should not fail")
+}
+
+/// Blake2b-256 digest of `intent_signing_bytes`
+pub fn intent_signing_digest<T: Serialize>(scope: IntentScope, value: &T) -> [u8; 32] {
+    DefaultHash::digest(intent_signing_bytes(scope, value)).into()
+}
+
+/// `intent_signing_digest` as a `Digest`, for any intent scope
+pub fn scoped_digest<T: Serialize>(scope: IntentScope, value: &T) -> Digest {
+    Digest::new(intent_signing_digest(scope, value))
+}
+
+/// `TransactionData::signing_digest` for each transaction, reusing one buffer and hasher
+pub fn batch_signing_digests(datas: &[TransactionData]) -> Result<Vec<[u8; 32]>, TransactionError> {
+    let mut buffer = Vec::new();
+    let mut hasher = DefaultHash::new();
+    datas
+        .iter()
+        .map(|data| {
+            data.ensure_resolved()?;
+            buffer.clear();
+            bcs::serialize_into(&mut buffer, &data.intent_message())
+                .expect("IntentMessage serialization should not fail");
+            hasher.update(&buffer);
+            Ok(hasher.finalize_reset().into())
+        })
+        .collect()
+}
+
 /// Sender signed data (simplified)
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct SenderSignedData {
@@ -458,36 +2080,248 @@ impl SenderSignedData {
     pub fn new(transactions: Vec<SenderSignedTransaction>) -> Self {
         Self { transactions }
     }
+
+    /// Split a batch into one single-transaction value per element
+    pub fn split(self) -> Vec<SenderSignedData> {
+        self.transactions
+            .into_iter()
+            .map(|transaction| Self::new(vec![transaction]))
+            .collect()
+    }
+
+    /// Number of transactions in the batch
+    pub fn len(&self) -> usize {
+        self.transactions.len()
+    }
+
+    /// Whether the batch holds no transactions
+    pub fn is_empty(&self) -> bool {
+        self.transactions.is_empty()
+    }
+
+    /// Hash of the transaction data alone, stable across different signature sets.
+    ///
+    /// Unlike `digest`, this ignores the intent and the signatures attached to each
+    /// transaction.
+    pub fn content_hash(&self) -> Digest {
+        let data: Vec<&TransactionData> = self
+            .transactions
+            .iter()
+            .map(|transaction| &transaction.intent_message.value)
+            .collect();
+        let mut hasher = DefaultHash::new();
+        bcs::serialize_into(&mut hasher, &data)
+            .expect("TransactionData serialization should not fail");
+        Digest::new(hasher.finalize().into())
+    }
 }
 
 impl Message for SenderSignedData {
     type DigestType = TransactionDigest;
 
     fn digest(&self) -> Self::DigestType {
-        // Simplified digest for minimal implementation
-        TransactionDigest::new([0u8; 32])
+        // Stream the BCS encoding straight into the hasher so large payloads are never buffered
+        let mut hasher = DefaultHash::new();
+        bcs::serialize_into(&mut hasher, self)
+            .expect("SenderSignedData serialization should not fail");
+        TransactionDigest::new(hasher.finalize().into())
+    }
+}
+
+/// BCS serializer that reuses one buffer across calls to avoid per-call allocation
+#[derive(Debug, Default)]
+pub struct TxSerializer {
+    buffer: Vec<u8>,
+}
+
+impl TxSerializer {
+    /// Create a new serializer with an empty buffer
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// BCS-encode `value` into the reused buffer, valid until the next call.
+    ///
+    /// Panics if `value` cannot be represented in BCS; every type in this crate can.
+    pub fn serialize<T: Serialize>(&mut self, value: &T) -> &[u8] {
+        self.buffer.clear();
+        bcs::serialize_into(&mut self.buffer, value).expect("BCS serialization should not fail");
+        &self.buffer
+    }
+
+    /// Same as `Message::digest`, but encoding through the reused buffer
+    pub fn digest(&mut self, data: &SenderSignedData) -> TransactionDigest {
+        let bytes = self.serialize(data);
+        TransactionDigest::new(DefaultHash::digest(bytes).into())
+    }
+}
+
+/// Number of digests a `DigestCache` keeps unless built with `DigestCache::with_capacity`
+#[cfg(feature = "digest-cache")]
+pub const DEFAULT_DIGEST_CACHE_CAPACITY: usize = 4096;
+
+/// Thread-safe memo of transaction digests shared across request handlers.
+///
+/// Entries are keyed by the `SenderSignedData` itself, held once behind an `Arc`, so a hit
+/// always compares the full data; once `capacity` digests are held the oldest is evicted.
+#[cfg(feature = "digest-cache")]
+#[derive(Debug)]
+pub struct DigestCache {
+    capacity: usize,
+    entries: std::sync::RwLock<DigestCacheEntries>,
+}
+
+#[cfg(feature = "digest-cache")]
+#[derive(Debug, Default)]
+struct DigestCacheEntries {
+    digests: HashMap<Arc<SenderSignedData>, TransactionDigest>,
+    insertion_order: std::collections::VecDeque<Arc<SenderSignedData>>,
+}
+
+#[cfg(feature = "digest-cache")]
+impl Default for DigestCache {
+    fn default() -> Self {
+        Self::with_capacity(DEFAULT_DIGEST_CACHE_CAPACITY)
+    }
+}
+
+#[cfg(feature = "digest-cache")]
+impl DigestCache {
+    /// Create a new, empty cache holding up to `DEFAULT_DIGEST_CACHE_CAPACITY` digests
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a new, empty cache holding up to `capacity` digests; zero disables caching
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: Default::default(),
+        }
+    }
+
+    /// Number of digests currently cached
+    pub fn len(&self) -> usize {
+        self.entries.read().unwrap().digests.len()
+    }
+
+    /// Whether the cache holds no digests
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Return the cached digest of `data`, computing and storing it on first use
+    pub fn get_or_compute(&self, data: &SenderSignedData) -> TransactionDigest {
+        if let Some(digest) = self.entries.read().unwrap().digests.get(data) {
+            return *digest;
+        }
+        let digest = data.digest();
+        if self.capacity == 0 {
+            return digest;
+        }
+        let mut entries = self.entries.write().unwrap();
+        if !entries.digests.contains_key(data) {
+            let key = Arc::new(data.clone());
+            entries.digests.insert(key.clone(), digest);
+            entries.insertion_order.push_back(key);
+            if entries.insertion_order.len() > self.capacity {
+                if let Some(oldest) = entries.insertion_order.pop_front() {
+                    entries.digests.remove(&oldest);
+                }
+            }
+        }
+        digest
+    }
+}
+
+/// A violation found by `TransactionData::check_against` or `Transaction::validate`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TransactionError {
+    InconsistentSharedVersion(ObjectID),
+    InconsistentSharedMutability(ObjectID),
+    TooLarge { size: usize, max: usize },
+    Serialization(String),
+    TooManyCommands { count: usize, max: usize },
+    PureArgumentTooLarge { size: usize, max: usize },
+    TypeArgumentTooDeep { type_argument: TypeTag, max: usize },
+    UnresolvedObject(ObjectID),
+    DuplicateObject(ObjectID),
+    EmptyGasPayment,
+    TooManyGasCoins { count: usize, max: usize },
+    GasBudgetOutOfRange { budget: u64, min: u64, max: u64 },
+    GasPriceBelowReference { price: u64, reference: u64 },
+}
+
+impl fmt::Display for TransactionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TransactionError::InconsistentSharedVersion(id) => write!(
+                f,
+                "Shared object {} used with inconsistent initial shared versions",
+                id
+            ),
+            TransactionError::InconsistentSharedMutability(id) => {
+                write!(f, "Shared object {} used as both mutable and immutable", id)
+            }
+            TransactionError::TooLarge { size, max } => write!(
+                f,
+                "Transaction size {} exceeds the maximum of {} bytes",
+                size, max
+            ),
+            TransactionError::Serialization(e) => {
+                write!(f, "Transaction serialization failed: {}", e)
+            }
+            TransactionError::TooManyCommands { count, max } => {
+                write!(f, "{} commands exceed the maximum of {}", count, max)
+            }
+            TransactionError::PureArgumentTooLarge { size, max } => write!(
+                f,
+                "Pure argument of {} bytes exceeds the maximum of {}",
+                size, max
+            ),
+            TransactionError::TypeArgumentTooDeep { type_argument, max } => write!(
+                f,
+                "Type argument {} exceeds the maximum depth of {}",
+                type_argument, max
+            ),
+            TransactionError::UnresolvedObject(id) => write!(f, "Object {} is unresolved", id),
+            TransactionError::DuplicateObject(id) => {
+                write!(f, "Object {} is used more than once", id)
+            }
+            TransactionError::EmptyGasPayment => write!(f, "Gas payment is empty"),
+            TransactionError::TooManyGasCoins { count, max } => {
+                write!(f, "{} gas coins exceed the maximum of {}", count, max)
+            }
+            TransactionError::GasBudgetOutOfRange { budget, min, max } => {
+                write!(f, "Gas budget {} is outside [{}, {}]", budget, min, max)
+            }
+            TransactionError::GasPriceBelowReference { price, reference } => write!(
+                f,
+                "Gas price {} is below the reference gas price {}",
+                price, reference
+            ),
+        }
     }
 }
 
+impl std::error::Error for TransactionError {}
+
 /// Transaction type alias
-pub type Transaction = Envelope<SenderSignedData, EmptySignInfo>;
+pub type Transaction = Envelope<SenderSignedData, Vec<SuiSignature>>;
 
 impl Transaction {
     /// Create a new transaction from data and signatures
-    pub fn from_data(data: SenderSignedData, _signatures: Vec<SuiSignature>) -> Self {
-        // For minimal implementation, we'll use EmptySignInfo
-        // In a real implementation, this would create proper signature info
-        Envelope::new_from_data_and_sig(data, EmptySignInfo {})
+    pub fn from_data(data: SenderSignedData, signatures: Vec<SuiSignature>) -> Self {
+        Envelope::new_from_data_and_sig(data, signatures)
     }
 
     /// Create a new transaction from TransactionData and signatures (convenience method)
     pub fn from_transaction_data(
         transaction_data: TransactionData,
-        _signatures: Vec<SuiSignature>,
+        signatures: Vec<SuiSignature>,
     ) -> Self {
         // Create the intent message
-        let intent = Intent::sui_app(IntentScope::TransactionData);
-        let intent_message = IntentMessage::new(intent, transaction_data);
+        let intent_message = transaction_data.into_intent_message();
         
         // Create the sender signed transaction
         let sender_signed_tx = SenderSignedTransaction::new(intent_message);
@@ -496,7 +2330,159 @@ impl Transaction {
         let sender_signed_data = SenderSignedData::new(vec![sender_signed_tx]);
         
         // Use the main from_data method
-        Self::from_data(sender_signed_data, _signatures)
+        Self::from_data(sender_signed_data, signatures)
+    }
+
+    /// Run all local checks before submission, returning every violation found
+    pub fn validate(
+        &self,
+        reference_gas_price: u64,
+        limits: &ProtocolLimits,
+    ) -> Result<(), Vec<TransactionError>> {
+        let mut errors = Vec::new();
+        for transaction in &self.data().transactions {
+            let data = &transaction.intent_message.value;
+            if let Err(errs) = data.check_against(limits) {
+                errors.extend(errs);
+            }
+            if data.gas_data.price < reference_gas_price {
+                errors.push(TransactionError::GasPriceBelowReference {
+                    price: data.gas_data.price,
+                    reference: reference_gas_price,
+                });
+            }
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Sign through an external signer such as a hardware wallet or KMS.
+    ///
+    /// `signer` receives the intent signing bytes and returns the raw signature, which is
+    /// tagged with the scheme of `public_key`. Data with unresolved object placeholders is
+    /// rejected before `signer` is called.
+    pub fn sign_with<F>(
+        data: TransactionData,
+        public_key: PublicKey,
+        signer: F,
+    ) -> Result<Transaction, eyre::Report>
+    where
+        F: FnOnce(&[u8]) -> Result<Vec<u8>, eyre::Report>,
+    {
+        data.ensure_resolved()?;
+        let signing_bytes = intent_signing_bytes(IntentScope::TransactionData, &data);
+        let signature_bytes = signer(&signing_bytes)?;
+        if signature_bytes.is_empty() {
+            return Err(eyre::eyre!("External signer returned an empty signature"));
+        }
+        let signature = SuiSignature {
+            scheme: public_key.scheme(),
+            signature_bytes,
+        };
+        Ok(Self::from_transaction_data(data, vec![signature]))
+    }
+
+    /// Clone the transaction without its signatures so it can be re-signed.
+    ///
+    /// Signatures never enter `SenderSignedData`, so the transaction digest is unchanged.
+    pub fn unsigned_clone(&self) -> Transaction {
+        Envelope::new_from_data_and_sig(self.data().clone(), vec![])
+    }
+
+    /// Encode as a self-contained fixture for bug reports.
+    ///
+    /// Layout: little-endian `u32` header length, a JSON header carrying the format version
+    /// and the CRC32 and length of the payload, then the BCS-encoded transaction.
+    pub fn to_fixture(&self) -> Vec<u8> {
+        let payload = bcs::to_bytes(self).expect("Transaction serialization should not fail");
+        let header = serde_json::to_vec(&FixtureHeader {
+            version: FIXTURE_FORMAT_VERSION,
+            crc32: crc32fast::hash(&payload),
+            len: payload.len() as u64,
+        })
+        .expect("FixtureHeader serialization should not fail");
+
+        let mut fixture = Vec::with_capacity(4 + header.len() + payload.len());
+        fixture.extend_from_slice(&(header.len() as u32).to_le_bytes());
+        fixture.extend_from_slice(&header);
+        fixture.extend_from_slice(&payload);
+        fixture
+    }
+
+    /// Decode a fixture produced by `to_fixture`, rejecting corrupted payloads
+    pub fn from_fixture(fixture: &[u8]) -> Result<Transaction, anyhow::Error> {
+        let (header_len, rest) = fixture
+            .split_first_chunk::<4>()
+            .ok_or_else(|| anyhow::anyhow!("Fixture is missing its header length"))?;
+        let header_len = u32::from_le_bytes(*header_len) as usize;
+        if rest.len() < header_len {
+            return Err(anyhow::anyhow!("Fixture header is truncated"));
+        }
+        let (header, payload) = rest.split_at(header_len);
+        let header: FixtureHeader = serde_json::from_slice(header)?;
+
+        if header.version != FIXTURE_FORMAT_VERSION {
+            return Err(anyhow::anyhow!(
+                "Unsupported fixture version {}",
+                header.version
+            ));
+        }
+        if payload.len() as u64 != header.len {
+            return Err(anyhow::anyhow!(
+                "Fixture payload is {} bytes, header says {}",
+                payload.len(),
+                header.len
+            ));
+        }
+        let crc32 = crc32fast::hash(payload);
+        if crc32 != header.crc32 {
+            return Err(anyhow::anyhow!(
+                "Fixture checksum mismatch: expected {:08x}, got {:08x}",
+                header.crc32,
+                crc32
+            ));
+        }
+        Ok(bcs::from_bytes(payload)?)
+    }
+
+    /// One-line summary for logs, cut to at most `max_bytes` on a char boundary
+    pub fn to_log_summary(&self, max_bytes: usize) -> String {
+        let mut summary = format!("Transaction {}", self.data().digest());
+        for transaction in &self.data().transactions {
+            let data = &transaction.intent_message.value;
+            let TransactionKind::ProgrammableTransaction(pt) = &data.kind;
+            summary.push_str(&format!(
+                " {{ sender: {}, commands: {}, gas_budget: {}, gas_price: {}, gas_coins: {} }}",
+                data.sender,
+                pt.commands.len(),
+                data.gas_data.budget,
+                data.gas_data.price,
+                data.gas_data.payment.len()
+            ));
+        }
+        if summary.len() > max_bytes {
+            let mut end = max_bytes;
+            while !summary.is_char_boundary(end) {
+                end -= 1;
+            }
+            summary.truncate(end);
+        }
+        summary
+    }
+
+    /// Create a new transaction from BCS-encoded TransactionData and its signature
+    pub fn from_signed_bytes(
+        tx_data_bcs: &[u8],
+        signature: SuiSignature,
+    ) -> Result<Transaction, anyhow::Error> {
+        let transaction_data: TransactionData = bcs::from_bytes(tx_data_bcs)?;
+        Ok(Self::from_transaction_data(
+            transaction_data,
+            vec![signature],
+        ))
     }
 
     /// Create a new transaction from TransactionData and signatures (alias for convenience)
@@ -508,6 +2494,17 @@ impl Transaction {
     }
 }
 
+/// Current layout version written by `Transaction::to_fixture`
+const FIXTURE_FORMAT_VERSION: u8 = 1;
+
+/// JSON header of a transaction fixture
+#[derive(Serialize, Deserialize)]
+struct FixtureHeader {
+    version: u8,
+    crc32: u32,
+    len: u64,
+}
+
 impl fmt::Display for TransactionData {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "TransactionData {{ sender: {}, gas_budget: {} }}", 
@@ -0,0 +1,346 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Human-named JSON for debugging, kept separate from the BCS-critical serde derives.
+//!
+//! Addresses, ids and digests render as `0x`-prefixed hex and amounts as decimal strings.
+
+use crate::base_types::SuiAddress;
+use crate::transaction::{
+    CallArg, Command, Digest, GasData, MoveCall, ObjectArg, ObjectDigest, ObjectID, ObjectRef,
+    ProgrammableTransaction, SequenceNumber, TransactionData, TransactionExpiration,
+    TransactionKind,
+};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// Verbose JSON form of `TransactionData`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionDataJson {
+    pub kind: TransactionKindJson,
+    pub sender: String,
+    pub gas_data: GasDataJson,
+    pub expiration: TransactionExpirationJson,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TransactionKindJson {
+    ProgrammableTransaction(ProgrammableTransactionJson),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProgrammableTransactionJson {
+    pub inputs: Vec<CallArgJson>,
+    pub commands: Vec<CommandJson>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum CallArgJson {
+    Pure {
+        bytes: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    ImmOrOwnedObject {
+        object_id: String,
+        version: String,
+        digest: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    SharedObject {
+        object_id: String,
+        initial_shared_version: String,
+        mutable: bool,
+    },
+    #[serde(rename_all = "camelCase")]
+    UnresolvedObject {
+        object_id: String,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CommandJson {
+    MoveCall(MoveCallJson),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MoveCallJson {
+    pub package: String,
+    pub module: String,
+    pub function: String,
+    pub type_arguments: Vec<String>,
+    pub arguments: Vec<CallArgJson>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GasDataJson {
+    pub payment: Vec<ObjectRefJson>,
+    pub owner: String,
+    pub price: String,
+    pub budget: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ObjectRefJson {
+    pub object_id: String,
+    pub version: String,
+    pub digest: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TransactionExpirationJson {
+    None,
+    Epoch(String),
+}
+
+impl TransactionData {
+    /// Serialize to verbose, human-named JSON
+    pub fn to_verbose_json(&self) -> serde_json::Value {
+        serde_json::to_value(TransactionDataJson::from(self))
+            .expect("TransactionDataJson serialization should not fail")
+    }
+
+    /// Parse JSON produced by `to_verbose_json`
+    pub fn from_verbose_json(value: &serde_json::Value) -> Result<Self, anyhow::Error> {
+        TransactionDataJson::deserialize(value)?.try_into()
+    }
+}
+
+fn digest_to_hex(digest: &Digest) -> String {
+    format!("0x{}", hex::encode(digest.inner()))
+}
+
+fn parse_amount(amount: &str) -> Result<u64, anyhow::Error> {
+    amount
+        .parse()
+        .map_err(|e| anyhow::anyhow!("Invalid amount {}: {}", amount, e))
+}
+
+impl From<&TransactionData> for TransactionDataJson {
+    fn from(data: &TransactionData) -> Self {
+        let TransactionKind::ProgrammableTransaction(pt) = &data.kind;
+        Self {
+            kind: TransactionKindJson::ProgrammableTransaction(pt.into()),
+            sender: data.sender.to_string(),
+            gas_data: (&data.gas_data).into(),
+            expiration: match data.expiration {
+                TransactionExpiration::None => TransactionExpirationJson::None,
+                TransactionExpiration::Epoch(epoch) => {
+                    TransactionExpirationJson::Epoch(epoch.to_string())
+                }
+            },
+        }
+    }
+}
+
+impl TryFrom<TransactionDataJson> for TransactionData {
+    type Error = anyhow::Error;
+
+    fn try_from(json: TransactionDataJson) -> Result<Self, Self::Error> {
+        let TransactionKindJson::ProgrammableTransaction(pt) = json.kind;
+        Ok(Self {
+            kind: TransactionKind::ProgrammableTransaction(pt.try_into()?),
+            sender: SuiAddress::from_str(&json.sender)?,
+            gas_data: json.gas_data.try_into()?,
+            expiration: match json.expiration {
+                TransactionExpirationJson::None => TransactionExpiration::None,
+                TransactionExpirationJson::Epoch(epoch) => {
+                    TransactionExpiration::Epoch(parse_amount(&epoch)?)
+                }
+            },
+        })
+    }
+}
+
+impl From<&ProgrammableTransaction> for ProgrammableTransactionJson {
+    fn from(pt: &ProgrammableTransaction) -> Self {
+        Self {
+            inputs: pt.inputs.iter().map(CallArgJson::from).collect(),
+            commands: pt
+                .commands
+                .iter()
+                .map(|command| match command {
+                    Command::MoveCall(move_call) => CommandJson::MoveCall(move_call.into()),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl TryFrom<ProgrammableTransactionJson> for ProgrammableTransaction {
+    type Error = anyhow::Error;
+
+    fn try_from(json: ProgrammableTransactionJson) -> Result<Self, Self::Error> {
+        let inputs = json
+            .inputs
+            .into_iter()
+            .map(CallArg::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+        let commands = json
+            .commands
+            .into_iter()
+            .map(|command| match command {
+                CommandJson::MoveCall(move_call) => move_call.try_into().map(Command::MoveCall),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self::new(inputs, commands))
+    }
+}
+
+impl From<&CallArg> for CallArgJson {
+    fn from(arg: &CallArg) -> Self {
+        match arg {
+            CallArg::Pure(bytes) => CallArgJson::Pure {
+                bytes: format!("0x{}", hex::encode(bytes)),
+            },
+            CallArg::Object(ObjectArg::ImmOrOwned(object_ref)) => CallArgJson::ImmOrOwnedObject {
+                object_id: object_ref.id.to_string(),
+                version: object_ref.version.value().to_string(),
+                digest: digest_to_hex(object_ref.digest.inner()),
+            },
+            CallArg::Object(ObjectArg::Shared {
+                id,
+                initial_shared_version,
+                mutable,
+            }) => CallArgJson::SharedObject {
+                object_id: id.to_string(),
+                initial_shared_version: initial_shared_version.value().to_string(),
+                mutable: *mutable,
+            },
+            CallArg::UnresolvedObject(id) => CallArgJson::UnresolvedObject {
+                object_id: id.to_string(),
+            },
+        }
+    }
+}
+
+impl TryFrom<CallArgJson> for CallArg {
+    type Error = anyhow::Error;
+
+    fn try_from(json: CallArgJson) -> Result<Self, Self::Error> {
+        match json {
+            CallArgJson::Pure { bytes } => Ok(CallArg::Pure(hex::decode(
+                bytes.strip_prefix("0x").unwrap_or(&bytes),
+            )?)),
+            CallArgJson::ImmOrOwnedObject {
+                object_id,
+                version,
+                digest,
+            } => Ok(CallArg::Object(ObjectArg::ImmOrOwned(
+                ObjectRefJson {
+                    object_id,
+                    version,
+                    digest,
+                }
+                .try_into()?,
+            ))),
+            CallArgJson::SharedObject {
+                object_id,
+                initial_shared_version,
+                mutable,
+            } => Ok(CallArg::Object(ObjectArg::new_shared(
+                ObjectID::from_str(&object_id)?,
+                SequenceNumber::new(parse_amount(&initial_shared_version)?),
+                mutable,
+            ))),
+            CallArgJson::UnresolvedObject { object_id } => {
+                Ok(CallArg::unresolved_object(ObjectID::from_str(&object_id)?))
+            }
+        }
+    }
+}
+
+impl From<&MoveCall> for MoveCallJson {
+    fn from(move_call: &MoveCall) -> Self {
+        Self {
+            package: move_call.package.to_string(),
+            module: move_call.module.clone(),
+            function: move_call.function.clone(),
+            type_arguments: move_call
+                .type_arguments
+                .iter()
+                .map(|type_argument| type_argument.to_string())
+                .collect(),
+            arguments: move_call.arguments.iter().map(CallArgJson::from).collect(),
+        }
+    }
+}
+
+impl TryFrom<MoveCallJson> for MoveCall {
+    type Error = anyhow::Error;
+
+    fn try_from(json: MoveCallJson) -> Result<Self, Self::Error> {
+        let type_args: Vec<&str> = json.type_arguments.iter().map(String::as_str).collect();
+        let arguments = json
+            .arguments
+            .into_iter()
+            .map(CallArg::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+        MoveCall::with_type_args_str(
+            ObjectID::from_str(&json.package)?,
+            &json.module,
+            &json.function,
+            &type_args,
+            arguments,
+        )
+    }
+}
+
+impl From<&GasData> for GasDataJson {
+    fn from(gas_data: &GasData) -> Self {
+        Self {
+            payment: gas_data.payment.iter().map(ObjectRefJson::from).collect(),
+            owner: gas_data.owner.to_string(),
+            price: gas_data.price.to_string(),
+            budget: gas_data.budget.to_string(),
+        }
+    }
+}
+
+impl TryFrom<GasDataJson> for GasData {
+    type Error = anyhow::Error;
+
+    fn try_from(json: GasDataJson) -> Result<Self, Self::Error> {
+        let payment = json
+            .payment
+            .into_iter()
+            .map(ObjectRef::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(GasData::new(
+            payment,
+            SuiAddress::from_str(&json.owner)?,
+            parse_amount(&json.price)?,
+            parse_amount(&json.budget)?,
+        ))
+    }
+}
+
+impl From<&ObjectRef> for ObjectRefJson {
+    fn from(object_ref: &ObjectRef) -> Self {
+        Self {
+            object_id: object_ref.id.to_string(),
+            version: object_ref.version.value().to_string(),
+            digest: digest_to_hex(object_ref.digest.inner()),
+        }
+    }
+}
+
+impl TryFrom<ObjectRefJson> for ObjectRef {
+    type Error = anyhow::Error;
+
+    fn try_from(json: ObjectRefJson) -> Result<Self, Self::Error> {
+        Ok(ObjectRef::new(
+            ObjectID::from_str(&json.object_id)?,
+            SequenceNumber::new(parse_amount(&json.version)?),
+            ObjectDigest::new(Digest::parse_any(&json.digest)?),
+        ))
+    }
+}
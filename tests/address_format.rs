@@ -0,0 +1,79 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Formatting `SuiAddress` must not allocate. This binary installs a counting allocator, so
+//! it holds only formatting tests.
+
+use minimal_sui_types::SuiAddress;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::fmt::Write;
+
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|count| count.set(count.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Fixed-capacity `fmt::Write` target on the stack
+struct StackBuffer {
+    bytes: [u8; 66],
+    len: usize,
+}
+
+impl Write for StackBuffer {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        let end = self.len + s.len();
+        self.bytes
+            .get_mut(self.len..end)
+            .ok_or(std::fmt::Error)?
+            .copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+#[test]
+fn display_matches_hex_literal() {
+    let address = SuiAddress::new([0xab; 32]);
+    assert_eq!(address.to_string(), address.to_hex_literal());
+    assert_eq!(address.to_string(), format!("0x{}", "ab".repeat(32)));
+}
+
+#[test]
+fn formatting_into_a_stack_buffer_does_not_allocate() {
+    let address = SuiAddress::new([0xab; 32]);
+    let mut buffer = StackBuffer {
+        bytes: [0; 66],
+        len: 0,
+    };
+
+    let before = ALLOCATIONS.with(Cell::get);
+    for _ in 0..10_000 {
+        buffer.len = 0;
+        write!(buffer, "{}", address).unwrap();
+    }
+    let allocations = ALLOCATIONS.with(Cell::get) - before;
+
+    assert_eq!(allocations, 0);
+    assert_eq!(&buffer.bytes[..], address.to_hex_literal().as_bytes());
+
+    // The counter does see allocations: `to_string` needs one for its `String`
+    let before = ALLOCATIONS.with(Cell::get);
+    let _ = address.to_string();
+    assert!(ALLOCATIONS.with(Cell::get) > before);
+}
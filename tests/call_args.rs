@@ -0,0 +1,102 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+mod common;
+
+use common::*;
+use minimal_sui_types::*;
+
+#[test]
+fn pure_option_encodes_as_move_option() {
+    assert_eq!(
+        CallArg::pure_option::<u64>(None).unwrap(),
+        CallArg::new_pure(vec![0])
+    );
+    assert_eq!(
+        CallArg::pure_option(Some(&5u64)).unwrap(),
+        CallArg::new_pure(vec![1, 5, 0, 0, 0, 0, 0, 0, 0])
+    );
+}
+
+#[test]
+fn object_ref_converts_into_owned_object_arg() {
+    let object_ref = object_ref(3, 7);
+    let arg = ObjectArg::from(object_ref);
+
+    assert_eq!(arg, ObjectArg::new_imm_or_owned(object_ref));
+    assert_eq!(arg.object_ref(), Some(&object_ref));
+}
+
+#[test]
+fn shared_object_arg_has_no_object_ref() {
+    let arg = ObjectArg::new_shared(object_id(4), SequenceNumber::new(1), true);
+    assert_eq!(arg.object_ref(), None);
+}
+
+#[test]
+fn validate_pure_as_checks_length_against_the_type() {
+    let short = CallArg::new_pure(vec![1, 0, 0, 0]);
+    let bytes = CallArg::new_pure(bcs::to_bytes(&vec![1u8, 2, 3]).unwrap());
+
+    assert!(pure_u64(7).validate_pure_as(&TypeTag::new_u64()).is_ok());
+    assert!(short.validate_pure_as(&TypeTag::new_u64()).is_err());
+    assert!(bytes
+        .validate_pure_as(&TypeTag::new_vector(TypeTag::U8))
+        .is_ok());
+    // A u64 has trailing bytes when read as a bool
+    assert!(pure_u64(1).validate_pure_as(&TypeTag::Bool).is_err());
+    assert!(owned(3).validate_pure_as(&TypeTag::new_u64()).is_err());
+}
+
+#[test]
+fn shared_object_arg_from_rpc_parts() {
+    let id = object_id(4).to_string();
+    let arg = ObjectArg::shared_from_parts(&id, 7, true).unwrap();
+
+    assert_eq!(
+        arg,
+        ObjectArg::new_shared(object_id(4), SequenceNumber::new(7), true)
+    );
+    assert!(ObjectArg::shared_from_parts("0xnot-hex", 7, true).is_err());
+}
+
+fn pure<T: serde::Serialize>(value: &T) -> CallArg {
+    CallArg::new_pure(bcs::to_bytes(value).unwrap())
+}
+
+#[test]
+fn typed_literals_encode_each_supported_type() {
+    let cases = [
+        ("u8:255", pure(&255u8)),
+        ("u64:1000", pure(&1000u64)),
+        ("u128:7", pure(&7u128)),
+        ("bool:true", pure(&true)),
+        ("address:0x2", pure(&SUI_FRAMEWORK)),
+        ("string:\"hello\"", pure(&"hello")),
+        ("vec<u8>:[1, 2,3]", pure(&vec![1u8, 2, 3])),
+        ("vec<u8>:[]", pure(&Vec::<u8>::new())),
+    ];
+    for (literal, expected) in cases {
+        assert_eq!(
+            CallArg::from_typed_literal(literal).unwrap(),
+            expected,
+            "{}",
+            literal
+        );
+    }
+}
+
+#[test]
+fn typed_literal_errors_name_the_failing_part() {
+    let error = |literal: &str| {
+        CallArg::from_typed_literal(literal)
+            .unwrap_err()
+            .to_string()
+    };
+
+    assert!(error("1000").contains("type:value"));
+    assert!(error("u8:256").contains("Invalid u8 value 256"));
+    assert!(error("vec<u8>:[1,x]").contains("Invalid vec<u8> element value x"));
+    assert!(error("string:hello").contains("must be quoted"));
+    assert!(error("u16:1").contains("Unsupported literal type u16"));
+}
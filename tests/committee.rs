@@ -0,0 +1,60 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use minimal_sui_types::committee::StakeUnit;
+use minimal_sui_types::crypto::PublicKey;
+use minimal_sui_types::{Committee, CommitteeDiff};
+
+fn key(byte: u8) -> PublicKey {
+    PublicKey::Ed25519([byte; 32])
+}
+
+#[test]
+fn diff_reports_a_joining_member() {
+    let before = Committee::new(vec![(key(1), 10), (key(2), 10)]);
+    let after = Committee::new(vec![(key(1), 10), (key(2), 10), (key(3), 5)]);
+
+    assert_eq!(
+        before.diff(&after),
+        CommitteeDiff {
+            added: vec![(key(3), 5)],
+            ..CommitteeDiff::default()
+        }
+    );
+}
+
+#[test]
+fn diff_reports_reweighted_and_removed_members() {
+    let before = Committee::new(vec![(key(1), 10), (key(2), 10)]);
+    let after = Committee::new(vec![(key(1), 25)]);
+
+    assert_eq!(
+        before.diff(&after),
+        CommitteeDiff {
+            removed: vec![(key(2), 10)],
+            reweighted: vec![(key(1), 10, 25)],
+            ..CommitteeDiff::default()
+        }
+    );
+    assert!(before.diff(&before).is_empty());
+}
+
+#[test]
+fn quorum_threshold_is_two_thirds_plus_one() {
+    let committee = Committee::new(vec![(key(1), 1), (key(2), 1), (key(3), 1), (key(4), 1)]);
+    assert_eq!(committee.total_power(), 4);
+    assert_eq!(committee.quorum_threshold(), 3);
+}
+
+#[test]
+fn large_voting_power_does_not_overflow() {
+    let committee = Committee::new(vec![(key(1), StakeUnit::MAX), (key(2), StakeUnit::MAX)]);
+    assert_eq!(committee.total_power(), StakeUnit::MAX);
+    assert_eq!(committee.quorum_threshold(), StakeUnit::MAX);
+
+    let single = Committee::new(vec![(key(1), StakeUnit::MAX)]);
+    assert_eq!(
+        single.quorum_threshold(),
+        (2 * StakeUnit::MAX as u128 / 3 + 1) as StakeUnit
+    );
+}
@@ -0,0 +1,87 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Fixtures shared by the integration tests. Each test binary uses a different subset.
+
+#![allow(dead_code)]
+
+use minimal_sui_types::*;
+
+pub fn address(byte: u8) -> SuiAddress {
+    SuiAddress::new([byte; 32])
+}
+
+pub fn object_id(byte: u8) -> ObjectID {
+    ObjectID::new(address(byte))
+}
+
+pub fn object_ref(byte: u8, version: u64) -> ObjectRef {
+    ObjectRef::new(
+        object_id(byte),
+        SequenceNumber::new(version),
+        ObjectDigest::new(Digest::new([byte; 32])),
+    )
+}
+
+pub fn owned(byte: u8) -> CallArg {
+    CallArg::new_object(ObjectArg::new_imm_or_owned(object_ref(byte, 1)))
+}
+
+pub fn shared(byte: u8, mutable: bool) -> CallArg {
+    CallArg::new_object(ObjectArg::new_shared(
+        object_id(byte),
+        SequenceNumber::new(1),
+        mutable,
+    ))
+}
+
+pub fn pure_u64(value: u64) -> CallArg {
+    CallArg::new_pure(bcs::to_bytes(&value).unwrap())
+}
+
+/// `0x2::<module>::<function>` with no type arguments
+pub fn move_call(module: &str, function: &str, arguments: Vec<CallArg>) -> Command {
+    Command::new_move_call(MoveCall::new_with_str(
+        ObjectID::new(SUI_FRAMEWORK),
+        module,
+        function,
+        vec![],
+        arguments,
+    ))
+}
+
+/// Address `0x2`
+pub const SUI_FRAMEWORK: SuiAddress = minimal_sui_types::base_types::SUI_FRAMEWORK_ADDRESS;
+
+/// Sender `0x01..01` paying with gas coin `0x99..99`
+pub fn transaction_data(inputs: Vec<CallArg>, commands: Vec<Command>) -> TransactionData {
+    TransactionData::new(
+        TransactionKind::new(ProgrammableTransaction::new(inputs, commands)),
+        address(1),
+        object_ref(0x99, 1),
+        10_000_000,
+        1_000,
+    )
+}
+
+pub fn programmable(data: &TransactionData) -> &ProgrammableTransaction {
+    let TransactionKind::ProgrammableTransaction(pt) = &data.kind;
+    pt
+}
+
+pub fn programmable_mut(data: &mut TransactionData) -> &mut ProgrammableTransaction {
+    let TransactionKind::ProgrammableTransaction(pt) = &mut data.kind;
+    pt
+}
+
+/// An Ed25519-tagged signature filled with `byte`
+pub fn signature(byte: u8) -> SuiSignature {
+    SuiSignature {
+        scheme: crypto::SignatureScheme::ED25519,
+        signature_bytes: vec![byte; 64],
+    }
+}
+
+pub fn transaction(data: TransactionData) -> Transaction {
+    Transaction::from_transaction_data(data, vec![])
+}
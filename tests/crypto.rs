@@ -0,0 +1,220 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use minimal_sui_types::crypto::{
+    generate_proof_of_possession, verify_proof_of_possession, PublicKey,
+};
+use minimal_sui_types::{SuiAddress, SuiKeyPair};
+
+#[test]
+fn public_key_from_keypair_matches_public() {
+    let keypairs = [
+        SuiKeyPair::Ed25519(vec![7; 32]),
+        SuiKeyPair::Secp256k1(vec![7; 32]),
+        SuiKeyPair::Secp256r1(vec![7; 32]),
+    ];
+    for keypair in &keypairs {
+        assert_eq!(PublicKey::from(keypair), keypair.public());
+    }
+}
+
+const KEYSTORE_FIXTURE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/sui.keystore");
+
+#[test]
+fn load_keystore_reads_two_key_fixture() {
+    let keys = SuiKeyPair::load_keystore(KEYSTORE_FIXTURE).unwrap();
+
+    let mut ed25519 = vec![0x00];
+    ed25519.extend(1..=32);
+    let mut secp256k1 = vec![0x01];
+    secp256k1.extend([0x11; 32]);
+    assert_eq!(keys.len(), 2);
+    assert_eq!(keys[0].to_bytes(), ed25519);
+    assert_eq!(keys[1].to_bytes(), secp256k1);
+}
+
+#[test]
+fn save_keystore_round_trips_through_load() {
+    let keys = SuiKeyPair::load_keystore(KEYSTORE_FIXTURE).unwrap();
+    let path = std::env::temp_dir().join(format!("sui-{}.keystore", std::process::id()));
+
+    SuiKeyPair::save_keystore(&path, &keys).unwrap();
+    let reloaded = SuiKeyPair::load_keystore(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    let bytes = |keys: &[SuiKeyPair]| keys.iter().map(SuiKeyPair::to_bytes).collect::<Vec<_>>();
+    assert_eq!(bytes(&reloaded), bytes(&keys));
+}
+
+#[test]
+fn load_keystore_reports_the_bad_entry() {
+    let path = std::env::temp_dir().join(format!("sui-bad-{}.keystore", std::process::id()));
+    std::fs::write(
+        &path,
+        r#"["AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8g", "not base64!"]"#,
+    )
+    .unwrap();
+    let err = SuiKeyPair::load_keystore(&path).unwrap_err();
+    std::fs::remove_file(&path).unwrap();
+
+    assert!(err.to_string().contains("entry 1"), "{}", err);
+}
+
+fn secret(hex: &str) -> Vec<u8> {
+    hex::decode(hex).unwrap()
+}
+
+#[test]
+fn public_keys_are_derived_from_the_secret() {
+    // RFC 8032 test 1
+    let ed25519 = SuiKeyPair::Ed25519(secret(
+        "9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60",
+    ));
+    assert_eq!(
+        hex::encode(ed25519.public().as_bytes()),
+        "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a"
+    );
+
+    // Secret key 1 yields each curve's generator point
+    let one = secret("0000000000000000000000000000000000000000000000000000000000000001");
+    assert_eq!(
+        hex::encode(SuiKeyPair::Secp256k1(one.clone()).public().as_bytes()),
+        "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798"
+    );
+    assert_eq!(
+        hex::encode(SuiKeyPair::Secp256r1(one).public().as_bytes()),
+        "036b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296"
+    );
+}
+
+#[test]
+fn fingerprint_is_stable_and_distinguishes_keys() {
+    let key = SuiKeyPair::Ed25519(vec![1; 32]);
+    let same = SuiKeyPair::Ed25519(vec![1; 32]);
+    let other = SuiKeyPair::Ed25519(vec![2; 32]);
+
+    assert_eq!(key.public().fingerprint(), same.public().fingerprint());
+    assert_ne!(key.public().fingerprint(), other.public().fingerprint());
+    // The scheme flag is hashed in, so equal key bytes under another scheme differ too
+    assert_ne!(
+        PublicKey::Ed25519([1; 32]).fingerprint(),
+        PublicKey::Secp256k1([1; 33]).fingerprint()[..]
+    );
+}
+
+#[test]
+fn public_identity_matches_the_derived_address() {
+    for key in [
+        SuiKeyPair::Ed25519(vec![1; 32]),
+        SuiKeyPair::Secp256k1(vec![1; 32]),
+        SuiKeyPair::Secp256r1(vec![1; 32]),
+    ] {
+        let public_key = key.public();
+        assert_eq!(
+            key.public_identity(),
+            (public_key.scheme(), SuiAddress::from(&public_key))
+        );
+    }
+    assert_ne!(
+        SuiKeyPair::Ed25519(vec![1; 32]).public_identity(),
+        SuiKeyPair::Ed25519(vec![2; 32]).public_identity()
+    );
+}
+
+#[test]
+fn from_bytes_rejects_invalid_secret_keys() {
+    let mut short = vec![0x00];
+    short.extend([1; 31]);
+    let mut zero_scalar = vec![0x01];
+    zero_scalar.extend([0; 32]);
+
+    assert!(SuiKeyPair::from_bytes(&short).is_err());
+    assert!(SuiKeyPair::from_bytes(&zero_scalar).is_err());
+}
+
+#[test]
+fn debug_shows_the_fingerprint_but_never_the_secret() {
+    let secret_bytes: Vec<u8> = (0xa0..0xc0).collect();
+    let key = SuiKeyPair::Ed25519(secret_bytes.clone());
+    let rendered = format!("{:?}", key);
+
+    assert_eq!(
+        rendered,
+        format!(
+            "SuiKeyPair::Ed25519(pub: 0x{})",
+            hex::encode(key.public().fingerprint())
+        )
+    );
+    assert!(!rendered.contains(&hex::encode(&secret_bytes)));
+    assert!(!rendered.contains(&format!("{:?}", secret_bytes)));
+}
+
+#[test]
+fn proof_of_possession_round_trips() {
+    for keypair in [
+        SuiKeyPair::Ed25519(vec![3; 32]),
+        SuiKeyPair::Secp256k1(vec![3; 32]),
+        SuiKeyPair::Secp256r1(vec![3; 32]),
+    ] {
+        let public_key = keypair.public();
+        let address = SuiAddress::from(&public_key);
+
+        let pop = generate_proof_of_possession(&keypair, &address);
+        assert_eq!(pop.scheme, public_key.scheme());
+        assert_eq!(pop.signature_bytes.len(), 64);
+        assert!(verify_proof_of_possession(&pop, &public_key, &address));
+
+        let other_key = SuiKeyPair::Ed25519(vec![4; 32]).public();
+        assert!(!verify_proof_of_possession(&pop, &other_key, &address));
+        let other_address = SuiAddress::from(&other_key);
+        assert!(!verify_proof_of_possession(
+            &pop,
+            &public_key,
+            &other_address
+        ));
+
+        let mut flipped = pop.clone();
+        flipped.signature_bytes[10] ^= 0x01;
+        assert!(!verify_proof_of_possession(&flipped, &public_key, &address));
+    }
+}
+
+#[test]
+fn proof_of_possession_for_another_key_is_rejected() {
+    let keypair = SuiKeyPair::Ed25519(vec![3; 32]);
+    let other = SuiKeyPair::Ed25519(vec![4; 32]);
+    let address = SuiAddress::from(&keypair.public());
+
+    let pop = generate_proof_of_possession(&other, &address);
+    assert!(!verify_proof_of_possession(
+        &pop,
+        &keypair.public(),
+        &address
+    ));
+}
+
+#[test]
+fn expanded_and_secret_ed25519_constructors_agree() {
+    // RFC 8032 test 1: the expanded form is secret || public
+    let expanded: [u8; 64] = secret(concat!(
+        "9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60",
+        "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a",
+    ))
+    .try_into()
+    .unwrap();
+    let secret_half: [u8; 32] = expanded[..32].try_into().unwrap();
+
+    let from_secret = SuiKeyPair::from_ed25519_secret(&secret_half);
+    let from_expanded = SuiKeyPair::from_ed25519_expanded(&expanded);
+    assert_eq!(from_secret.public().as_bytes(), &expanded[32..]);
+    assert_eq!(from_expanded.public(), from_secret.public());
+    assert_eq!(from_expanded.to_bytes(), from_secret.to_bytes());
+
+    // The public half is ignored rather than checked
+    let mut mismatched = expanded;
+    mismatched[32..].fill(0);
+    assert_eq!(
+        SuiKeyPair::from_ed25519_expanded(&mismatched).public(),
+        from_secret.public()
+    );
+}
@@ -0,0 +1,65 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+#![cfg(feature = "digest-cache")]
+
+mod common;
+
+use common::*;
+use minimal_sui_types::message_envelope::Message;
+use minimal_sui_types::transaction::{DigestCache, DEFAULT_DIGEST_CACHE_CAPACITY};
+use minimal_sui_types::*;
+
+fn signed_data(budget: u64) -> SenderSignedData {
+    let mut data = transaction_data(vec![owned(3)], vec![move_call("m", "f", vec![])]);
+    data.gas_data.budget = budget;
+    SenderSignedData::new(vec![SenderSignedTransaction::new(
+        data.into_intent_message(),
+    )])
+}
+
+#[test]
+fn concurrent_lookups_agree_with_the_direct_digest() {
+    let cache = DigestCache::new();
+    let samples: Vec<SenderSignedData> = (0..8).map(|i| signed_data(1_000_000 + i)).collect();
+
+    std::thread::scope(|scope| {
+        for _ in 0..8 {
+            scope.spawn(|| {
+                for _ in 0..100 {
+                    for data in &samples {
+                        assert_eq!(cache.get_or_compute(data), data.digest());
+                    }
+                }
+            });
+        }
+    });
+    assert_eq!(cache.len(), samples.len());
+}
+
+#[test]
+fn cache_evicts_the_oldest_digest_past_capacity() {
+    let cache = DigestCache::with_capacity(2);
+    for budget in 0..5 {
+        let data = signed_data(budget);
+        assert_eq!(cache.get_or_compute(&data), data.digest());
+    }
+    assert_eq!(cache.len(), 2);
+
+    let disabled = DigestCache::with_capacity(0);
+    disabled.get_or_compute(&signed_data(0));
+    assert!(disabled.is_empty());
+}
+
+#[test]
+fn distinct_data_never_shares_a_cached_digest() {
+    let cache = DigestCache::with_capacity(DEFAULT_DIGEST_CACHE_CAPACITY);
+    let samples: Vec<SenderSignedData> = (0..256).map(signed_data).collect();
+    for data in &samples {
+        cache.get_or_compute(data);
+    }
+    for data in &samples {
+        assert_eq!(cache.get_or_compute(data), data.digest());
+    }
+    assert_eq!(cache.len(), samples.len());
+}
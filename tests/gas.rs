@@ -0,0 +1,207 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+mod common;
+
+use common::*;
+use minimal_sui_types::limits::{MAX_GAS_BUDGET, MIN_GAS_BUDGET};
+use minimal_sui_types::transaction::{
+    budget_from_gas_used, detect_gas_conflicts, select_gas_coins, GasCoin, GasError,
+};
+use minimal_sui_types::*;
+
+#[test]
+fn tip_raises_the_gas_price() {
+    let mut gas = GasData::new(vec![object_ref(1, 1)], address(1), 1_000, 10_000_000);
+    gas.with_tip(250);
+    assert_eq!(gas.price, 1_250);
+}
+
+#[test]
+fn tip_saturates_instead_of_overflowing() {
+    let mut gas = GasData::new(vec![object_ref(1, 1)], address(1), u64::MAX - 1, 10_000_000);
+    gas.with_tip(10);
+    assert_eq!(gas.price, u64::MAX);
+}
+
+#[test]
+fn effective_gas_price_never_drops_below_reference() {
+    let data = transaction_data(vec![], vec![]);
+    assert_eq!(data.effective_gas_price(750), 1_000);
+    assert_eq!(data.effective_gas_price(1_500), 1_500);
+}
+
+#[test]
+fn gas_smashing_keeps_the_first_coin_and_consumes_the_rest() {
+    let coins = vec![object_ref(1, 1), object_ref(2, 1), object_ref(3, 1)];
+    let gas = GasData::new(coins.clone(), address(1), 1_000, 10_000_000);
+
+    assert_eq!(gas.smashed_coin(), Some(coins[0]));
+    assert_eq!(gas.smashed_away(), &coins[1..]);
+}
+
+#[test]
+fn gas_smashing_without_payment_has_no_survivor() {
+    let gas = GasData::new(vec![], address(1), 1_000, 10_000_000);
+    assert_eq!(gas.smashed_coin(), None);
+    assert!(gas.smashed_away().is_empty());
+}
+
+#[test]
+fn gas_coin_split_deducts_from_the_balance() {
+    let mut coin = GasCoin::new(object_ref(0x99, 1), 1_000);
+
+    assert_eq!(coin.split_off(400).unwrap(), 400);
+    assert_eq!(coin.balance(), 600);
+    assert_eq!(coin.split_off(600).unwrap(), 600);
+    assert_eq!(coin.balance(), 0);
+}
+
+#[test]
+fn gas_coin_overdraw_is_rejected_and_keeps_the_balance() {
+    let mut coin = GasCoin::new(object_ref(0x99, 1), 1_000);
+
+    let err = coin.split_off(1_001).unwrap_err();
+    assert_eq!(
+        err,
+        GasError::InsufficientBalance {
+            balance: 1_000,
+            amount: 1_001
+        }
+    );
+    assert!(err.to_string().contains("1000 < 1001"), "{}", err);
+    assert_eq!(coin.balance(), 1_000);
+}
+
+#[test]
+fn gas_coins_pair_payment_with_looked_up_balances() {
+    let mut data = transaction_data(vec![], vec![move_call("m", "f", vec![])]);
+    data.gas_data.payment = vec![object_ref(0x99, 1), object_ref(0x98, 2)];
+
+    let gas_coins = data
+        .gas_coins(|object_ref| Some(object_ref.version.value() * 100))
+        .unwrap();
+    assert_eq!(
+        gas_coins,
+        vec![
+            GasCoin::new(object_ref(0x99, 1), 100),
+            GasCoin::new(object_ref(0x98, 2), 200),
+        ]
+    );
+}
+
+#[test]
+fn gas_coins_fail_on_a_coin_without_a_balance() {
+    let mut data = transaction_data(vec![], vec![move_call("m", "f", vec![])]);
+    data.gas_data.payment = vec![object_ref(0x99, 1), object_ref(0x98, 2)];
+
+    let err = data
+        .gas_coins(|object_ref| (object_ref.version.value() == 1).then_some(1_000))
+        .unwrap_err();
+    assert_eq!(err, GasError::MissingCoin(object_ref(0x98, 2).id));
+}
+
+fn coins(balances: &[u64]) -> Vec<(ObjectRef, u64)> {
+    balances
+        .iter()
+        .enumerate()
+        .map(|(i, balance)| (object_ref(i as u8 + 1, 1), *balance))
+        .collect()
+}
+
+#[test]
+fn select_gas_coins_takes_largest_first_until_covered() {
+    let available = coins(&[300, 500, 200]);
+
+    assert_eq!(
+        select_gas_coins(&available, 800, 3).unwrap(),
+        vec![object_ref(2, 1), object_ref(1, 1)]
+    );
+    assert_eq!(
+        select_gas_coins(&available, 500, 3).unwrap(),
+        vec![object_ref(2, 1)]
+    );
+}
+
+#[test]
+fn select_gas_coins_reports_insufficient_funds() {
+    let err = select_gas_coins(&coins(&[300, 500, 200]), 1_001, 3).unwrap_err();
+    assert!(err.to_string().contains("1000 < 1001"), "{}", err);
+}
+
+#[test]
+fn select_gas_coins_respects_the_coin_cap() {
+    let available = coins(&[300, 500, 200]);
+
+    assert_eq!(
+        select_gas_coins(&available, 900, 2).unwrap_err(),
+        GasError::TooManyCoins {
+            budget: 900,
+            max_coins: 2
+        }
+    );
+    assert_eq!(select_gas_coins(&available, 800, 2).unwrap().len(), 2);
+}
+
+#[test]
+fn heavier_transactions_suggest_a_higher_minimum_budget() {
+    let calls = |count: usize| (0..count).map(|_| move_call("m", "f", vec![])).collect();
+    let light = transaction_data(vec![], calls(10));
+    let heavy = transaction_data(vec![owned(3), owned(4)], calls(20));
+
+    let (light_min, light_max) = light.suggested_budget_range(1_000);
+    let (heavy_min, heavy_max) = heavy.suggested_budget_range(1_000);
+    assert!(heavy_min > light_min);
+    assert_eq!(light_max, MAX_GAS_BUDGET);
+    assert_eq!(heavy_max, MAX_GAS_BUDGET);
+
+    let empty = transaction_data(vec![], vec![]);
+    assert_eq!(empty.suggested_budget_range(1_000).0, MIN_GAS_BUDGET);
+}
+
+#[test]
+fn budget_from_gas_used_nets_the_rebate_against_storage() {
+    assert_eq!(
+        budget_from_gas_used(2_000_000, 3_000_000, 1_000_000, 0),
+        4_000_000
+    );
+    // The rebate can only offset storage, never computation
+    assert_eq!(
+        budget_from_gas_used(2_000_000, 1_000_000, 5_000_000, 0),
+        2_000_000
+    );
+}
+
+#[test]
+fn budget_from_gas_used_applies_the_margin_and_clamps() {
+    assert_eq!(
+        budget_from_gas_used(2_000_000, 3_000_000, 1_000_000, 1_000),
+        4_400_000
+    );
+    assert_eq!(budget_from_gas_used(1, 0, 0, 0), MIN_GAS_BUDGET);
+    assert_eq!(
+        budget_from_gas_used(u64::MAX, u64::MAX, 0, u16::MAX),
+        MAX_GAS_BUDGET
+    );
+}
+
+#[test]
+fn detect_gas_conflicts_pairs_transactions_sharing_a_coin() {
+    let paying_with = |coins: &[u8]| {
+        let mut data = transaction_data(vec![], vec![]);
+        data.gas_data.payment = coins.iter().map(|coin| object_ref(*coin, 1)).collect();
+        data
+    };
+    let batch = vec![
+        paying_with(&[0x90]),
+        paying_with(&[0x91]),
+        paying_with(&[0x92, 0x90]),
+        paying_with(&[0x92, 0x92]),
+    ];
+
+    assert_eq!(
+        detect_gas_conflicts(&batch),
+        vec![(0, 2, object_id(0x90)), (2, 3, object_id(0x92))]
+    );
+    assert!(detect_gas_conflicts(&batch[..2]).is_empty());
+}
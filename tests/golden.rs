@@ -0,0 +1,248 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Golden BCS encodings for the public wire types.
+//!
+//! Any change to a type's serialized layout fails this test with the new hex. After an
+//! intentional layout change, run `BLESS=1 cargo test --test golden` to rewrite the
+//! expected values below.
+
+use minimal_sui_types::crypto::{BasicSignature, SignatureScheme};
+use minimal_sui_types::*;
+
+#[rustfmt::skip]
+const EXPECTED: &[(&str, &str)] = &[
+    ("SuiAddress", "1111111111111111111111111111111111111111111111111111111111111111"),
+    ("ObjectID", "2222222222222222222222222222222222222222222222222222222222222222"),
+    ("SequenceNumber", "0700000000000000"),
+    ("ObjectDigest", "3333333333333333333333333333333333333333333333333333333333333333"),
+    ("Digest", "5555555555555555555555555555555555555555555555555555555555555555"),
+    ("ObjectRef", "222222222222222222222222222222222222222222222222222222222222222207000000000000003333333333333333333333333333333333333333333333333333333333333333"),
+    ("ObjectArg::ImmOrOwned", "00222222222222222222222222222222222222222222222222222222222222222207000000000000003333333333333333333333333333333333333333333333333333333333333333"),
+    ("ObjectArg::Shared", "014444444444444444444444444444444444444444444444444444444444444444030000000000000001"),
+    ("CallArg::Pure", "0003010203"),
+    ("CallArg::Object", "01014444444444444444444444444444444444444444444444444444444444444444030000000000000001"),
+    ("CallArg::UnresolvedObject", "022222222222222222222222222222222222222222222222222222222222222222"),
+    ("TypeTag", "0506020202020202020202020202020202020202020202020202020202020202020204636f696e04436f696e0102"),
+    ("StructTag", "020202020202020202020202020202020202020202020202020202020202020204636f696e04436f696e0102"),
+    ("MoveCall", "0202020202020202020202020202020202020202020202020202020202020202037061790573706c6974010506020202020202020202020202020202020202020202020202020202020202020204636f696e04436f696e010202000301020301014444444444444444444444444444444444444444444444444444444444444444030000000000000001"),
+    ("Command", "000202020202020202020202020202020202020202020202020202020202020202037061790573706c6974010506020202020202020202020202020202020202020202020202020202020202020204636f696e04436f696e010202000301020301014444444444444444444444444444444444444444444444444444444444444444030000000000000001"),
+    ("ProgrammableTransaction", "01010022222222222222222222222222222222222222222222222222222222222222220700000000000000333333333333333333333333333333333333333333333333333333333333333301000202020202020202020202020202020202020202020202020202020202020202037061790573706c6974010506020202020202020202020202020202020202020202020202020202020202020204636f696e04436f696e010202000301020301014444444444444444444444444444444444444444444444444444444444444444030000000000000001"),
+    ("TransactionKind", "0001010022222222222222222222222222222222222222222222222222222222222222220700000000000000333333333333333333333333333333333333333333333333333333333333333301000202020202020202020202020202020202020202020202020202020202020202037061790573706c6974010506020202020202020202020202020202020202020202020202020202020202020204636f696e04436f696e010202000301020301014444444444444444444444444444444444444444444444444444444444444444030000000000000001"),
+    ("GasData", "012222222222222222222222222222222222222222222222222222222222222222070000000000000033333333333333333333333333333333333333333333333333333333333333331111111111111111111111111111111111111111111111111111111111111111e80300000000000040420f0000000000"),
+    ("TransactionExpiration::None", "00"),
+    ("TransactionExpiration::Epoch", "012a00000000000000"),
+    ("TransactionData", "0001010022222222222222222222222222222222222222222222222222222222222222220700000000000000333333333333333333333333333333333333333333333333333333333333333301000202020202020202020202020202020202020202020202020202020202020202037061790573706c6974010506020202020202020202020202020202020202020202020202020202020202020204636f696e04436f696e0102020003010203010144444444444444444444444444444444444444444444444444444444444444440300000000000000011111111111111111111111111111111111111111111111111111111111111111012222222222222222222222222222222222222222222222222222222222222222070000000000000033333333333333333333333333333333333333333333333333333333333333331111111111111111111111111111111111111111111111111111111111111111e80300000000000040420f0000000000012a00000000000000"),
+    ("Intent", "010100"),
+    ("AppId", "01"),
+    ("IntentMessage", "0000000001010022222222222222222222222222222222222222222222222222222222222222220700000000000000333333333333333333333333333333333333333333333333333333333333333301000202020202020202020202020202020202020202020202020202020202020202037061790573706c6974010506020202020202020202020202020202020202020202020202020202020202020204636f696e04436f696e0102020003010203010144444444444444444444444444444444444444444444444444444444444444440300000000000000011111111111111111111111111111111111111111111111111111111111111111012222222222222222222222222222222222222222222222222222222222222222070000000000000033333333333333333333333333333333333333333333333333333333333333331111111111111111111111111111111111111111111111111111111111111111e80300000000000040420f0000000000012a00000000000000"),
+    ("SenderSignedData", "010000000001010022222222222222222222222222222222222222222222222222222222222222220700000000000000333333333333333333333333333333333333333333333333333333333333333301000202020202020202020202020202020202020202020202020202020202020202037061790573706c6974010506020202020202020202020202020202020202020202020202020202020202020204636f696e04436f696e0102020003010203010144444444444444444444444444444444444444444444444444444444444444440300000000000000011111111111111111111111111111111111111111111111111111111111111111012222222222222222222222222222222222222222222222222222222222222222070000000000000033333333333333333333333333333333333333333333333333333333333333331111111111111111111111111111111111111111111111111111111111111111e80300000000000040420f0000000000012a00000000000000"),
+    ("Transaction", "010000000001010022222222222222222222222222222222222222222222222222222222222222220700000000000000333333333333333333333333333333333333333333333333333333333333333301000202020202020202020202020202020202020202020202020202020202020202037061790573706c6974010506020202020202020202020202020202020202020202020202020202020202020204636f696e04436f696e0102020003010203010144444444444444444444444444444444444444444444444444444444444444440300000000000000011111111111111111111111111111111111111111111111111111111111111111012222222222222222222222222222222222222222222222222222222222222222070000000000000033333333333333333333333333333333333333333333333333333333333333331111111111111111111111111111111111111111111111111111111111111111e80300000000000040420f0000000000012a0000000000000001000466666666"),
+    ("EmptySignInfo", ""),
+    ("SignatureScheme", "02"),
+    ("BasicSignature", "000466666666"),
+];
+
+fn address() -> SuiAddress {
+    SuiAddress::new([0x11; 32])
+}
+
+fn object_ref() -> ObjectRef {
+    ObjectRef::new(
+        ObjectID::new(SuiAddress::new([0x22; 32])),
+        SequenceNumber::new(7),
+        ObjectDigest::new(Digest::new([0x33; 32])),
+    )
+}
+
+fn shared_object() -> ObjectArg {
+    ObjectArg::new_shared(
+        ObjectID::new(SuiAddress::new([0x44; 32])),
+        SequenceNumber::new(3),
+        true,
+    )
+}
+
+fn type_tag() -> TypeTag {
+    TypeTag::new_vector(TypeTag::new_struct(struct_tag()))
+}
+
+fn struct_tag() -> StructTag {
+    StructTag::new(
+        SuiAddress::new([0x02; 32]),
+        "coin".to_string(),
+        "Coin".to_string(),
+        vec![TypeTag::new_u64()],
+    )
+}
+
+fn move_call() -> MoveCall {
+    MoveCall::new_with_str(
+        ObjectID::new(SuiAddress::new([0x02; 32])),
+        "pay",
+        "split",
+        vec![type_tag()],
+        vec![
+            CallArg::new_pure(vec![1, 2, 3]),
+            CallArg::new_object(shared_object()),
+        ],
+    )
+}
+
+fn programmable_transaction() -> ProgrammableTransaction {
+    ProgrammableTransaction::new(
+        vec![CallArg::new_object(ObjectArg::new_imm_or_owned(
+            object_ref(),
+        ))],
+        vec![Command::new_move_call(move_call())],
+    )
+}
+
+fn transaction_data() -> TransactionData {
+    let mut data = TransactionData::new(
+        TransactionKind::new(programmable_transaction()),
+        address(),
+        object_ref(),
+        1_000_000,
+        1_000,
+    );
+    data.expiration = TransactionExpiration::new_epoch(42);
+    data
+}
+
+fn intent_message() -> IntentMessage<TransactionData> {
+    IntentMessage::new(
+        Intent::sui_app(IntentScope::TransactionData),
+        transaction_data(),
+    )
+}
+
+fn sender_signed_data() -> SenderSignedData {
+    SenderSignedData::new(vec![SenderSignedTransaction::new(intent_message())])
+}
+
+fn signature() -> BasicSignature {
+    BasicSignature {
+        scheme: SignatureScheme::ED25519,
+        signature_bytes: vec![0x66; 4],
+    }
+}
+
+fn golden_values() -> Vec<(&'static str, Vec<u8>)> {
+    fn bcs<T: serde::Serialize>(value: &T) -> Vec<u8> {
+        bcs::to_bytes(value).unwrap()
+    }
+
+    vec![
+        ("SuiAddress", bcs(&address())),
+        ("ObjectID", bcs(&object_ref().id)),
+        ("SequenceNumber", bcs(&object_ref().version)),
+        ("ObjectDigest", bcs(&object_ref().digest)),
+        ("Digest", bcs(&Digest::new([0x55; 32]))),
+        ("ObjectRef", bcs(&object_ref())),
+        (
+            "ObjectArg::ImmOrOwned",
+            bcs(&ObjectArg::new_imm_or_owned(object_ref())),
+        ),
+        ("ObjectArg::Shared", bcs(&shared_object())),
+        ("CallArg::Pure", bcs(&CallArg::new_pure(vec![1, 2, 3]))),
+        (
+            "CallArg::Object",
+            bcs(&CallArg::new_object(shared_object())),
+        ),
+        (
+            "CallArg::UnresolvedObject",
+            bcs(&CallArg::unresolved_object(object_ref().id)),
+        ),
+        ("TypeTag", bcs(&type_tag())),
+        ("StructTag", bcs(&struct_tag())),
+        ("MoveCall", bcs(&move_call())),
+        ("Command", bcs(&Command::new_move_call(move_call()))),
+        ("ProgrammableTransaction", bcs(&programmable_transaction())),
+        (
+            "TransactionKind",
+            bcs(&TransactionKind::new(programmable_transaction())),
+        ),
+        ("GasData", bcs(&transaction_data().gas_data)),
+        (
+            "TransactionExpiration::None",
+            bcs(&TransactionExpiration::new_none()),
+        ),
+        (
+            "TransactionExpiration::Epoch",
+            bcs(&TransactionExpiration::new_epoch(42)),
+        ),
+        ("TransactionData", bcs(&transaction_data())),
+        (
+            "Intent",
+            bcs(&Intent::with_version(1, IntentScope::ProofOfPossession)),
+        ),
+        ("AppId", bcs(&AppId::Narwhal)),
+        ("IntentMessage", bcs(&intent_message())),
+        ("SenderSignedData", bcs(&sender_signed_data())),
+        (
+            "Transaction",
+            bcs(&Transaction::from_data(
+                sender_signed_data(),
+                vec![signature()],
+            )),
+        ),
+        ("EmptySignInfo", bcs(&EmptySignInfo {})),
+        ("SignatureScheme", bcs(&SignatureScheme::Secp256r1)),
+        ("BasicSignature", bcs(&signature())),
+    ]
+}
+
+fn bless(actual: &[(&str, String)]) {
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/golden.rs");
+    let source = std::fs::read_to_string(path).unwrap();
+    let start_marker = "const EXPECTED: &[(&str, &str)] = &[\n";
+    let start = source.find(start_marker).unwrap() + start_marker.len();
+    let end = start + source[start..].find("];\n").unwrap();
+    let entries: String = actual
+        .iter()
+        .map(|(name, hex)| format!("    (\"{}\", \"{}\"),\n", name, hex))
+        .collect();
+    let blessed = format!("{}{}{}", &source[..start], entries, &source[end..]);
+    std::fs::write(path, blessed).unwrap();
+}
+
+#[test]
+fn golden_bcs_encodings() {
+    let actual: Vec<(&str, String)> = golden_values()
+        .into_iter()
+        .map(|(name, bytes)| (name, hex::encode(bytes)))
+        .collect();
+
+    if std::env::var_os("BLESS").is_some() {
+        bless(&actual);
+        return;
+    }
+
+    let mut mismatches = Vec::new();
+    for (name, hex) in &actual {
+        match EXPECTED
+            .iter()
+            .find(|(expected_name, _)| expected_name == name)
+        {
+            Some((_, expected)) if expected == hex => {}
+            Some((_, expected)) => {
+                mismatches.push(format!("{}: expected {}, got {}", name, expected, hex))
+            }
+            None => mismatches.push(format!("{}: no golden value, got {}", name, hex)),
+        }
+    }
+    for (expected_name, _) in EXPECTED {
+        if !actual.iter().any(|(name, _)| name == expected_name) {
+            mismatches.push(format!(
+                "{}: golden value is no longer produced",
+                expected_name
+            ));
+        }
+    }
+    assert!(
+        mismatches.is_empty(),
+        "BCS layout changed; rerun with BLESS=1 if intentional:\n{}",
+        mismatches.join("\n")
+    );
+}
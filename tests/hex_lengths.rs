@@ -0,0 +1,90 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+mod common;
+
+use common::*;
+use minimal_sui_types::*;
+use std::str::FromStr;
+
+/// `0x` followed by `len` hex digits, so a 64-digit input is the full `0xab..ab` address
+fn hex(len: usize) -> String {
+    format!(
+        "0x{}",
+        "ab".repeat(33).chars().take(len).collect::<String>()
+    )
+}
+
+const LENGTHS: [(usize, bool); 3] = [(63, false), (64, true), (65, false)];
+
+#[test]
+fn address_and_object_id_from_str_need_exactly_64_digits() {
+    for (len, ok) in LENGTHS {
+        assert_eq!(SuiAddress::from_str(&hex(len)).is_ok(), ok, "{}", len);
+        assert_eq!(ObjectID::from_str(&hex(len)).is_ok(), ok, "{}", len);
+    }
+}
+
+#[test]
+fn hex_literal_rejects_near_full_addresses_of_the_wrong_length() {
+    for (len, ok) in LENGTHS {
+        assert_eq!(
+            SuiAddress::from_hex_literal(&hex(len)).is_ok(),
+            ok,
+            "{}",
+            len
+        );
+    }
+    assert_eq!(
+        SuiAddress::from_hex_literal(&hex(64)).unwrap(),
+        SuiAddress::new([0xab; 32])
+    );
+    assert_eq!(SuiAddress::from_hex_literal("0x2").unwrap(), SUI_FRAMEWORK);
+    assert!(SuiAddress::from_hex_literal(&hex(32)).is_ok());
+    assert!(SuiAddress::from_hex_literal(&hex(33)).is_err());
+}
+
+#[test]
+fn hex_digests_need_exactly_64_digits() {
+    for (len, ok) in LENGTHS {
+        assert_eq!(Digest::parse_any(&hex(len)).is_ok(), ok, "{}", len);
+    }
+    for input in [hex(63), format!("0x{}", "g".repeat(64))] {
+        let err = Digest::parse_any(&input).unwrap_err().to_string();
+        assert_eq!(
+            err,
+            format!("Invalid hex digest {}: expected 64 hex digits", input)
+        );
+    }
+    for len in [31, 33] {
+        let base58 = bs58::encode(vec![0xab; len]).into_string();
+        assert!(Digest::parse_any(&base58).is_err(), "{}", len);
+    }
+}
+
+#[test]
+fn struct_tag_and_typed_literal_addresses_need_full_length() {
+    for (len, ok) in LENGTHS {
+        let tag = format!("{}::coin::COIN", hex(len));
+        assert_eq!(StructTag::from_str(&tag).is_ok(), ok, "{}", len);
+        let literal = format!("address:{}", hex(len));
+        assert_eq!(CallArg::from_typed_literal(&literal).is_ok(), ok, "{}", len);
+    }
+}
+
+#[test]
+fn recipe_targets_need_full_length_packages() {
+    for (len, ok) in LENGTHS {
+        let recipe = serde_json::json!({
+            "moveCall": { "target": format!("{}::m::f", hex(len)), "args": [] }
+        });
+        let data = TransactionData::from_recipe(
+            &recipe,
+            address(1),
+            object_ref(0x99, 1),
+            10_000_000,
+            1_000,
+        );
+        assert_eq!(data.is_ok(), ok, "{}", len);
+    }
+}
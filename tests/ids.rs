@@ -0,0 +1,122 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+mod common;
+
+use common::*;
+use minimal_sui_types::*;
+
+#[test]
+fn derived_object_ids_are_stable_and_distinct_per_index() {
+    let tx_digest = Digest::new([0x55; 32]);
+    let first = ObjectID::derive_from(&tx_digest, 0);
+
+    assert_eq!(first, ObjectID::derive_from(&tx_digest, 0));
+    assert_ne!(first, ObjectID::derive_from(&tx_digest, 1));
+    assert_ne!(first, ObjectID::derive_from(&Digest::new([0x56; 32]), 0));
+}
+
+#[test]
+fn object_ref_fixed_bytes_layout_round_trips() {
+    let object_ref = object_ref(0x22, 0x0102);
+    let bytes = object_ref.to_fixed_bytes();
+
+    assert_eq!(bytes.len(), ObjectRef::FIXED_BYTES_LEN);
+    assert_eq!(&bytes[..32], &[0x22; 32]);
+    assert_eq!(&bytes[32..40], &[0, 0, 0, 0, 0, 0, 1, 2]);
+    assert_eq!(&bytes[40..], &[0x22; 32]);
+    assert_eq!(ObjectRef::from_fixed_bytes(bytes), object_ref);
+}
+
+#[test]
+fn sui_address_try_from_bytes_requires_exactly_32() {
+    assert_eq!(
+        SuiAddress::try_from(&[7u8; 32][..]).unwrap(),
+        SuiAddress::new([7; 32])
+    );
+    assert_eq!(
+        SuiAddress::try_from(vec![7u8; 32]).unwrap(),
+        SuiAddress::new([7; 32])
+    );
+    for len in [31, 33] {
+        assert!(SuiAddress::try_from(&vec![7u8; len][..]).is_err());
+        assert!(SuiAddress::try_from(vec![7u8; len]).is_err());
+    }
+}
+
+#[test]
+fn parse_any_accepts_base58_and_hex_digests() {
+    let digest = Digest::new([0x5a; 32]);
+    let base58 = bs58::encode(digest.inner()).into_string();
+    let hex = format!("0x{}", hex::encode(digest.inner()));
+
+    assert_eq!(Digest::parse_any(&base58).unwrap(), digest);
+    assert_eq!(Digest::parse_any(&hex).unwrap(), digest);
+}
+
+#[test]
+fn parse_any_rejects_strings_valid_in_neither_encoding() {
+    // Hex digits without the 0x prefix are read as Base58, and `0` is not a Base58 digit
+    assert!(Digest::parse_any(&"0".repeat(64)).is_err());
+    assert!(Digest::parse_any("0xnothex").is_err());
+    assert!(Digest::parse_any("").is_err());
+}
+
+#[test]
+fn effects_digest_is_stable_and_tracks_its_inputs() {
+    let tx_digest = Digest::new([7; 32]);
+    let created = [object_id(1), object_id(2)];
+    let effects = tx_digest.derive_effects_digest(0, &created);
+
+    assert_eq!(tx_digest.derive_effects_digest(0, &created), effects);
+    assert_ne!(tx_digest.derive_effects_digest(0, &created[..1]), effects);
+    assert_ne!(
+        tx_digest.derive_effects_digest(0, &[object_id(2), object_id(1)]),
+        effects
+    );
+    assert_ne!(tx_digest.derive_effects_digest(1, &created), effects);
+    assert_ne!(
+        Digest::new([8; 32]).derive_effects_digest(0, &created),
+        effects
+    );
+}
+
+#[test]
+fn object_refs_sort_by_id_then_version_then_digest() {
+    let with_digest = |byte: u8, version: u64, digest: u8| {
+        ObjectRef::new(
+            object_id(byte),
+            SequenceNumber::new(version),
+            ObjectDigest::new(Digest::new([digest; 32])),
+        )
+    };
+    let mut refs = vec![
+        with_digest(2, 1, 0),
+        with_digest(1, 9, 9),
+        with_digest(1, 3, 5),
+        with_digest(1, 3, 4),
+    ];
+    refs.sort();
+
+    assert_eq!(
+        refs,
+        vec![
+            with_digest(1, 3, 4),
+            with_digest(1, 3, 5),
+            with_digest(1, 9, 9),
+            with_digest(2, 1, 0),
+        ]
+    );
+}
+
+#[test]
+fn short_id_is_a_prefix_of_the_base58_digest() {
+    let digest = Digest::new([0x42; 32]);
+    let short = digest.short_id();
+
+    assert_eq!(short.len(), minimal_sui_types::transaction::SHORT_ID_LEN);
+    assert!(digest.to_string().starts_with(&short));
+    assert!(digest.matches_short_id(&short));
+    assert!(!digest.matches_short_id(&digest.to_string()));
+    assert!(!Digest::new([0x43; 32]).matches_short_id(&short));
+}
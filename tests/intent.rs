@@ -0,0 +1,108 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+mod common;
+
+use blake2::Digest as _;
+use common::*;
+use minimal_sui_types::transaction::{
+    batch_signing_digests, intent_signing_bytes, intent_signing_digest, scoped_digest,
+};
+use minimal_sui_types::*;
+
+#[test]
+fn transaction_data_intent_message_uses_the_transaction_intent() {
+    let data = transaction_data(vec![owned(3)], vec![]);
+
+    let borrowed = data.intent_message();
+    assert_eq!(borrowed.intent.scope, IntentScope::TransactionData);
+    assert_eq!(borrowed.intent.version, 0);
+    assert_eq!(borrowed.intent.app_id, AppId::Sui);
+    assert_eq!(borrowed.value, &data);
+
+    let owned = data.clone().into_intent_message();
+    assert_eq!(owned.intent, borrowed.intent);
+    assert_eq!(owned.value, data);
+}
+
+#[test]
+fn intent_message_parts_split_signing_bytes_at_the_three_byte_intent() {
+    let message = transaction_data(vec![owned(3)], vec![]).into_intent_message();
+    let (intent, value) = message.parts().unwrap();
+
+    // scope TransactionData, version 0, app id Sui
+    assert_eq!(intent, vec![0, 0, 0]);
+    assert_eq!(value, bcs::to_bytes(&message.value).unwrap());
+    assert_eq!([intent, value].concat(), message.signing_bytes().unwrap());
+}
+
+#[test]
+fn proof_of_possession_signing_bytes_prefix_the_scope_intent() {
+    let public_key = SuiKeyPair::Ed25519(vec![1; 32]).public();
+    let value = public_key.as_bytes().to_vec();
+
+    let bytes = intent_signing_bytes(IntentScope::ProofOfPossession, &value);
+    let mut expected = vec![1, 0, 0];
+    expected.extend(bcs::to_bytes(&value).unwrap());
+    assert_eq!(bytes, expected);
+
+    let digest: [u8; 32] = blake2::Blake2b::<blake2::digest::consts::U32>::digest(&bytes).into();
+    assert_eq!(
+        intent_signing_digest(IntentScope::ProofOfPossession, &value),
+        digest
+    );
+    assert_ne!(
+        intent_signing_digest(IntentScope::TransactionData, &value),
+        digest
+    );
+}
+
+#[test]
+fn batch_signing_digests_match_individual_digests() {
+    let datas = vec![
+        transaction_data(vec![], vec![]),
+        transaction_data(vec![owned(3)], vec![move_call("m", "f", vec![pure_u64(1)])]),
+        transaction_data(vec![CallArg::new_pure(vec![7; 300])], vec![]),
+    ];
+    let individual: Vec<[u8; 32]> = datas
+        .iter()
+        .map(|data| data.signing_digest().unwrap())
+        .collect();
+
+    assert_eq!(batch_signing_digests(&datas).unwrap(), individual);
+    assert_eq!(
+        individual[1],
+        intent_signing_digest(IntentScope::TransactionData, &datas[1])
+    );
+}
+
+#[test]
+fn scoped_digest_separates_scopes_for_the_same_value() {
+    let data = transaction_data(vec![owned(3)], vec![]);
+
+    let as_transaction = scoped_digest(IntentScope::TransactionData, &data);
+    let as_proof = scoped_digest(IntentScope::ProofOfPossession, &data);
+    assert_ne!(as_transaction, as_proof);
+    assert_eq!(
+        as_transaction,
+        Digest::new(intent_signing_digest(IntentScope::TransactionData, &data))
+    );
+    assert_eq!(
+        scoped_digest(IntentScope::ProofOfPossession, &data),
+        as_proof
+    );
+}
+
+#[test]
+fn signing_digests_reject_unresolved_placeholders() {
+    let placeholder = CallArg::unresolved_object(object_id(5));
+    let unresolved = transaction_data(vec![], vec![move_call("m", "f", vec![placeholder])]);
+    let expected = TransactionError::UnresolvedObject(object_id(5));
+
+    assert_eq!(unresolved.ensure_resolved(), Err(expected.clone()));
+    assert_eq!(unresolved.signing_digest(), Err(expected.clone()));
+    assert_eq!(
+        batch_signing_digests(&[transaction_data(vec![], vec![]), unresolved]),
+        Err(expected)
+    );
+}
@@ -0,0 +1,44 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+#![cfg(feature = "mnemonic")]
+
+use base64::Engine;
+use minimal_sui_types::{SuiAddress, SuiKeyPair};
+use std::str::FromStr;
+
+// From the Sui CLI keytool tests
+const PHRASE: &str = "film crazy soon outside stand loop subway crumble thrive popular green nuclear struggle pistol arm wife phrase warfare march wheat nephew ask sunny firm";
+
+#[test]
+fn mnemonic_derives_the_sui_cli_ed25519_address() {
+    let keypair = SuiKeyPair::from_mnemonic_ed25519(PHRASE, 0).unwrap();
+
+    assert_eq!(
+        keypair.to_bytes(),
+        base64::engine::general_purpose::STANDARD
+            .decode("AN0JMHpDum3BhrVwnkylH0/HGRHBQ/fO/8+MYOawO8j6")
+            .unwrap()
+    );
+    assert_eq!(
+        SuiAddress::from(&keypair.public()),
+        SuiAddress::from_str("0xa2d14fad60c56049ecf75246a481934691214ce413e6a8ae2fe6834c173a6133")
+            .unwrap()
+    );
+}
+
+#[test]
+fn mnemonic_accounts_derive_distinct_keys() {
+    let first = SuiKeyPair::from_mnemonic_ed25519(PHRASE, 0).unwrap();
+    let second = SuiKeyPair::from_mnemonic_ed25519(PHRASE, 1).unwrap();
+    assert_ne!(first.public(), second.public());
+}
+
+#[test]
+fn invalid_mnemonics_and_accounts_are_rejected() {
+    // Valid words with a bad checksum
+    let reordered = PHRASE.replacen("film crazy", "crazy film", 1);
+    assert!(SuiKeyPair::from_mnemonic_ed25519(&reordered, 0).is_err());
+    assert!(SuiKeyPair::from_mnemonic_ed25519("not a mnemonic", 0).is_err());
+    assert!(SuiKeyPair::from_mnemonic_ed25519(PHRASE, 0x8000_0000).is_err());
+}
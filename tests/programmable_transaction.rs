@@ -0,0 +1,340 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+mod common;
+
+use common::*;
+use minimal_sui_types::*;
+
+#[test]
+fn shared_object_used_mutably_and_immutably_conflicts() {
+    let pt = ProgrammableTransaction::new(
+        vec![shared(4, true)],
+        vec![move_call("m", "read", vec![shared(4, false)])],
+    );
+    assert_eq!(
+        pt.validate_shared_mutability(),
+        Err(TransactionError::InconsistentSharedMutability(object_id(4)))
+    );
+}
+
+#[test]
+fn shared_object_with_different_initial_versions_conflicts() {
+    let other_version = CallArg::new_object(ObjectArg::new_shared(
+        object_id(4),
+        SequenceNumber::new(2),
+        true,
+    ));
+    let pt = ProgrammableTransaction::new(
+        vec![shared(4, true)],
+        vec![move_call("m", "write", vec![other_version])],
+    );
+    assert_eq!(
+        pt.validate_shared_mutability(),
+        Err(TransactionError::InconsistentSharedVersion(object_id(4)))
+    );
+}
+
+#[test]
+fn consistent_shared_object_uses_pass() {
+    let pt = ProgrammableTransaction::new(
+        vec![shared(4, true), shared(5, false)],
+        vec![
+            move_call("m", "write", vec![shared(4, true)]),
+            move_call("m", "read", vec![shared(5, false)]),
+        ],
+    );
+    assert_eq!(pt.validate_shared_mutability(), Ok(()));
+}
+
+fn generic_call(function: &str, type_arguments: Vec<TypeTag>) -> Command {
+    Command::new_move_call(MoveCall::new_with_str(
+        object_id(0x2),
+        "coin",
+        function,
+        type_arguments,
+        vec![],
+    ))
+}
+
+#[test]
+fn type_arguments_collects_in_command_order_with_optional_dedup() {
+    let sui = TypeTag::new_struct(StructTag::sui_type());
+    let pt = ProgrammableTransaction::new(
+        vec![],
+        vec![
+            generic_call("split", vec![sui.clone()]),
+            generic_call("join", vec![sui.clone(), TypeTag::new_u64()]),
+        ],
+    );
+
+    assert_eq!(
+        pt.type_arguments(false),
+        vec![sui.clone(), sui.clone(), TypeTag::new_u64()]
+    );
+    assert_eq!(pt.type_arguments(true), vec![sui, TypeTag::new_u64()]);
+}
+
+#[test]
+fn commands_render_one_line_each() {
+    let pt = ProgrammableTransaction::new(
+        vec![],
+        vec![
+            move_call("coin", "split", vec![pure_u64(5)]),
+            move_call("transfer", "public_transfer", vec![owned(3)]),
+        ],
+    );
+    let lines: Vec<String> = pt.commands.iter().map(ToString::to_string).collect();
+
+    let framework = format!("0x{}2", "0".repeat(63));
+    assert_eq!(
+        lines,
+        vec![
+            format!("MoveCall({}::coin::split)", framework),
+            format!("MoveCall({}::transfer::public_transfer)", framework),
+        ]
+    );
+    assert_eq!(object_id(0x2).to_string(), format!("0x{}", "02".repeat(32)));
+}
+
+#[test]
+fn more_object_inputs_raise_the_storage_estimate() {
+    let one = ProgrammableTransaction::new(vec![owned(3)], vec![]);
+    let three = ProgrammableTransaction::new(vec![owned(3), owned(4), shared(5, true)], vec![]);
+    let repeated = ProgrammableTransaction::new(
+        vec![owned(3)],
+        vec![move_call("m", "f", vec![owned(3), pure_u64(1)])],
+    );
+
+    assert!(three.estimated_storage_units() > one.estimated_storage_units());
+    assert_eq!(
+        repeated.estimated_storage_units(),
+        one.estimated_storage_units()
+    );
+    assert_eq!(
+        ProgrammableTransaction::new(vec![pure_u64(1)], vec![]).estimated_storage_units(),
+        0
+    );
+}
+
+fn framework_call(function: &str) -> MoveCall {
+    MoveCall::new_with_str(ObjectID::new(SUI_FRAMEWORK), "m", function, vec![], vec![])
+}
+
+#[test]
+fn single_move_call_round_trips_through_transaction_kind() {
+    let kind = TransactionKind::from_single_move_call(framework_call("f"));
+
+    let TransactionKind::ProgrammableTransaction(pt) = &kind;
+    assert!(pt.inputs.is_empty());
+    assert_eq!(pt.commands.len(), 1);
+    assert_eq!(kind.as_single_move_call(), Some(&framework_call("f")));
+}
+
+#[test]
+fn as_single_move_call_rejects_inputs_and_extra_commands() {
+    let with_input = TransactionKind::new(ProgrammableTransaction::new(
+        vec![owned(3)],
+        vec![Command::new_move_call(framework_call("f"))],
+    ));
+    let two_calls = TransactionKind::new(ProgrammableTransaction::new(
+        vec![],
+        vec![
+            Command::new_move_call(framework_call("f")),
+            Command::new_move_call(framework_call("g")),
+        ],
+    ));
+
+    assert_eq!(with_input.as_single_move_call(), None);
+    assert_eq!(two_calls.as_single_move_call(), None);
+}
+
+#[test]
+fn move_call_converts_into_a_one_command_kind() {
+    let kind = TransactionKind::from(framework_call("f"));
+    assert_eq!(
+        kind,
+        TransactionKind::from_single_move_call(framework_call("f"))
+    );
+}
+
+#[test]
+fn commands_convert_into_a_transaction_without_inputs() {
+    let commands = vec![
+        move_call("m", "f", vec![owned(3)]),
+        move_call("m", "g", vec![]),
+    ];
+    let pt = ProgrammableTransaction::from(commands.clone());
+
+    assert!(pt.inputs.is_empty());
+    assert_eq!(pt.commands, commands);
+}
+
+#[test]
+fn remap_package_rewrites_targets_and_type_arguments() {
+    let (from, to) = (object_id(0xaa), object_id(0xbb));
+    let token = |package: u8| {
+        TypeTag::new_struct(StructTag::new(
+            address(package),
+            "token".to_string(),
+            "TOKEN".to_string(),
+            vec![],
+        ))
+    };
+    let mut pt = ProgrammableTransaction::new(
+        vec![],
+        vec![
+            Command::new_move_call(MoveCall::new_with_str(
+                from,
+                "token",
+                "mint",
+                vec![token(0xaa)],
+                vec![],
+            )),
+            generic_call("zero", vec![TypeTag::new_vector(token(0xaa))]),
+        ],
+    );
+    pt.remap_package(from, to);
+
+    let Command::MoveCall(mint) = &pt.commands[0];
+    assert_eq!(mint.package, to);
+    assert_eq!(mint.type_arguments, vec![token(0xbb)]);
+    let Command::MoveCall(zero) = &pt.commands[1];
+    assert_eq!(zero.package, object_id(0x2));
+    assert_eq!(zero.type_arguments, vec![TypeTag::new_vector(token(0xbb))]);
+}
+
+#[test]
+fn kind_names_label_transactions_and_commands() {
+    let pt = ProgrammableTransaction::new(
+        vec![],
+        vec![
+            move_call("coin", "split", vec![]),
+            move_call("transfer", "public_transfer", vec![]),
+            move_call("coin", "join", vec![]),
+        ],
+    );
+
+    assert_eq!(pt.commands[0].kind_name(), "MoveCall");
+    assert_eq!(
+        pt.command_kind_counts(),
+        std::collections::HashMap::from([("MoveCall", 3)])
+    );
+    assert!(ProgrammableTransaction::new(vec![], vec![])
+        .command_kind_counts()
+        .is_empty());
+    assert_eq!(
+        TransactionKind::new(pt).kind_name(),
+        "ProgrammableTransaction"
+    );
+}
+
+#[test]
+fn framework_share_object_calls_may_create_shared_objects() {
+    for function in ["share_object", "public_share_object"] {
+        let pt =
+            ProgrammableTransaction::from(vec![move_call("transfer", function, vec![owned(3)])]);
+        assert!(pt.may_create_shared_objects(), "{}", function);
+    }
+
+    let transfer =
+        ProgrammableTransaction::from(vec![move_call("transfer", "public_transfer", vec![])]);
+    let other_package =
+        ProgrammableTransaction::from(vec![Command::new_move_call(MoveCall::new_with_str(
+            object_id(0xaa),
+            "transfer",
+            "public_share_object",
+            vec![],
+            vec![],
+        ))]);
+    assert!(!transfer.may_create_shared_objects());
+    assert!(!other_package.may_create_shared_objects());
+}
+
+#[test]
+fn primary_move_call_is_the_first_command() {
+    let data = transaction_data(
+        vec![],
+        vec![
+            move_call("coin", "split", vec![pure_u64(5)]),
+            move_call("pool", "swap", vec![]),
+        ],
+    );
+    let call = data.primary_move_call().unwrap();
+    assert_eq!(
+        (call.module.as_str(), call.function.as_str()),
+        ("coin", "split")
+    );
+    assert_eq!(programmable(&data).primary_move_call(), Some(call));
+
+    assert_eq!(
+        transaction_data(vec![owned(3)], vec![]).primary_move_call(),
+        None
+    );
+}
+
+#[test]
+fn resolve_objects_fills_in_placeholders() {
+    let mut data = transaction_data(
+        vec![CallArg::unresolved_object(object_id(3))],
+        vec![move_call(
+            "m",
+            "f",
+            vec![CallArg::unresolved_object(object_id(4)), pure_u64(1)],
+        )],
+    );
+    assert!(data.ensure_resolved().is_err());
+
+    programmable_mut(&mut data)
+        .resolve_objects(|id| {
+            Some(ObjectRef::new(
+                id,
+                SequenceNumber::new(8),
+                ObjectDigest::new(Digest::new([9; 32])),
+            ))
+        })
+        .unwrap();
+
+    assert_eq!(data.ensure_resolved(), Ok(()));
+    let resolved = |byte: u8| {
+        CallArg::new_object(ObjectArg::new_imm_or_owned(ObjectRef::new(
+            object_id(byte),
+            SequenceNumber::new(8),
+            ObjectDigest::new(Digest::new([9; 32])),
+        )))
+    };
+    assert_eq!(programmable(&data).inputs, vec![resolved(3)]);
+    let Command::MoveCall(call) = &programmable(&data).commands[0];
+    assert_eq!(call.arguments, vec![resolved(4), pure_u64(1)]);
+}
+
+#[test]
+fn resolve_objects_reports_placeholders_left_unresolved() {
+    let mut pt = ProgrammableTransaction::new(
+        vec![
+            CallArg::unresolved_object(object_id(3)),
+            CallArg::unresolved_object(object_id(4)),
+        ],
+        vec![],
+    );
+
+    let err = pt
+        .resolve_objects(|id| (id == object_id(3)).then(|| object_ref(3, 2)))
+        .unwrap_err();
+    assert!(
+        err.to_string().contains(&object_id(4).to_string()),
+        "{}",
+        err
+    );
+    assert!(
+        !err.to_string().contains(&object_id(3).to_string()),
+        "{}",
+        err
+    );
+    assert_eq!(
+        pt.inputs[0],
+        CallArg::new_object(ObjectArg::new_imm_or_owned(object_ref(3, 2)))
+    );
+    // A reference for a different object is not accepted
+    assert!(pt.resolve_objects(|_| Some(object_ref(5, 1))).is_err());
+}
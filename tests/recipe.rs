@@ -0,0 +1,89 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+mod common;
+
+use common::*;
+use minimal_sui_types::*;
+
+fn compile(recipe: serde_json::Value) -> Result<TransactionData, anyhow::Error> {
+    TransactionData::from_recipe(&recipe, address(1), object_ref(0x99, 1), 10_000_000, 1_000)
+}
+
+#[test]
+fn move_call_recipe_compiles_type_args_and_literals() {
+    let data = compile(serde_json::json!({
+        "moveCall": {
+            "target": "0x2::coin::zero",
+            "typeArgs": ["0x2::sui::SUI"],
+            "args": ["u64:5", "bool:true"]
+        }
+    }))
+    .unwrap();
+
+    let expected = Command::new_move_call(MoveCall::new_with_str(
+        ObjectID::new(SUI_FRAMEWORK),
+        "coin",
+        "zero",
+        vec![TypeTag::new_struct(StructTag::sui_type())],
+        vec![
+            pure_u64(5),
+            CallArg::from_typed_literal("bool:true").unwrap(),
+        ],
+    ));
+    assert_eq!(data, transaction_data(vec![], vec![expected]));
+}
+
+#[test]
+fn split_and_transfer_recipe_compiles_object_arguments() {
+    let coin = object_ref(3, 7);
+    let data = compile(serde_json::json!([
+        { "moveCall": {
+            "target": "0x2::pay::split",
+            "args": [
+                { "object": coin.id.to_string(), "version": 7, "digest": Digest::new([3; 32]).to_string() },
+                "u64:100"
+            ]
+        } },
+        { "moveCall": {
+            "target": "0x2::transfer::public_transfer",
+            "args": [
+                { "sharedObject": object_id(4).to_string(), "initialSharedVersion": 1, "mutable": true },
+                { "unresolvedObject": object_id(5).to_string() },
+                format!("address:{}", address(2))
+            ]
+        } }
+    ]))
+    .unwrap();
+
+    let expected = vec![
+        move_call(
+            "pay",
+            "split",
+            vec![
+                CallArg::new_object(ObjectArg::new_imm_or_owned(coin)),
+                pure_u64(100),
+            ],
+        ),
+        move_call(
+            "transfer",
+            "public_transfer",
+            vec![
+                shared(4, true),
+                CallArg::UnresolvedObject(object_id(5)),
+                CallArg::new_pure(bcs::to_bytes(&address(2)).unwrap()),
+            ],
+        ),
+    ];
+    assert_eq!(data, transaction_data(vec![], expected));
+}
+
+#[test]
+fn malformed_recipes_are_rejected() {
+    assert!(compile(serde_json::json!({ "transferObjects": {} })).is_err());
+    assert!(compile(serde_json::json!({ "moveCall": { "target": "0x2::coin" } })).is_err());
+    assert!(compile(serde_json::json!({
+        "moveCall": { "target": "0x2::coin::zero", "args": [{ "object": "0x3" }] }
+    }))
+    .is_err());
+}
@@ -0,0 +1,255 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+mod common;
+
+use common::*;
+use minimal_sui_types::message_envelope::Message;
+use minimal_sui_types::*;
+
+#[test]
+fn unsigned_clone_keeps_digest_and_drops_signatures() {
+    let tx = Transaction::from_transaction_data(
+        transaction_data(vec![owned(3)], vec![]),
+        vec![signature(0x66)],
+    );
+    let stripped = tx.unsigned_clone();
+
+    assert_eq!(tx.auth_signature().len(), 1);
+    assert!(stripped.auth_signature().is_empty());
+    assert_eq!(stripped.data().digest(), tx.data().digest());
+}
+
+#[test]
+fn streamed_digest_matches_buffered_digest_for_large_payload() {
+    use blake2::Digest as _;
+
+    // Stand-in for a large publish: 100 KiB of module bytes in pure arguments
+    let modules = (0..4)
+        .map(|i| CallArg::new_pure(vec![i; 25 * 1024]))
+        .collect();
+    let data = transaction(transaction_data(modules, vec![])).into_data();
+
+    let buffered: [u8; 32] = crypto::DefaultHash::digest(bcs::to_bytes(&data).unwrap()).into();
+    assert_eq!(data.digest(), Digest::new(buffered));
+}
+
+#[test]
+fn from_signed_bytes_keeps_the_data_and_signature() {
+    use ed25519_dalek::{Signer, Verifier};
+
+    let signing_key = ed25519_dalek::SigningKey::from_bytes(&[3; 32]);
+    let data = transaction_data(vec![owned(3)], vec![]);
+    let digest = data.signing_digest().unwrap();
+    let signature = SuiSignature {
+        scheme: crypto::SignatureScheme::ED25519,
+        signature_bytes: signing_key.sign(&digest).to_vec(),
+    };
+
+    let tx =
+        Transaction::from_signed_bytes(&bcs::to_bytes(&data).unwrap(), signature.clone()).unwrap();
+
+    assert_eq!(tx.data().transactions.len(), 1);
+    assert_eq!(tx.data().transactions[0].intent_message.value, data);
+    assert_eq!(tx.auth_signature(), &vec![signature.clone()]);
+
+    let verify = |bytes: &[u8]| {
+        let signature = ed25519_dalek::Signature::from_slice(bytes).unwrap();
+        signing_key.verifying_key().verify(&digest, &signature)
+    };
+    assert!(verify(&tx.auth_signature()[0].signature_bytes).is_ok());
+    let mut tampered = signature.signature_bytes;
+    tampered[0] ^= 0x01;
+    assert!(verify(&tampered).is_err());
+}
+
+#[test]
+fn from_signed_bytes_rejects_malformed_data() {
+    assert!(Transaction::from_signed_bytes(&[0xff, 0x00], signature(0x77)).is_err());
+}
+
+fn signed_transaction(data: TransactionData) -> SenderSignedTransaction {
+    SenderSignedTransaction::new(data.into_intent_message())
+}
+
+#[test]
+fn split_yields_one_single_transaction_batch_each() {
+    let first = transaction_data(vec![owned(3)], vec![]);
+    let second = transaction_data(vec![owned(4)], vec![]);
+    let batch = SenderSignedData::new(vec![
+        signed_transaction(first.clone()),
+        signed_transaction(second.clone()),
+    ]);
+    assert_eq!(batch.len(), 2);
+
+    let parts = batch.split();
+    assert_eq!(parts.len(), 2);
+    assert!(parts.iter().all(|part| part.len() == 1));
+    assert_eq!(parts[0].transactions[0].intent_message.value, first);
+    assert_eq!(parts[1].transactions[0].intent_message.value, second);
+    assert_ne!(parts[0].digest(), parts[1].digest());
+
+    assert!(SenderSignedData::new(vec![]).is_empty());
+    assert!(SenderSignedData::new(vec![]).split().is_empty());
+}
+
+#[test]
+fn content_hash_ignores_signatures_and_intent() {
+    let data = transaction_data(vec![owned(3)], vec![]);
+    let signed_once = Transaction::from_transaction_data(data.clone(), vec![signature(1)]);
+    let signed_twice =
+        Transaction::from_transaction_data(data.clone(), vec![signature(2), signature(3)]);
+    assert_eq!(
+        signed_once.data().content_hash(),
+        signed_twice.data().content_hash()
+    );
+
+    let plain = SenderSignedData::new(vec![signed_transaction(data.clone())]);
+    let other_intent = SenderSignedData::new(vec![SenderSignedTransaction::new(
+        IntentMessage::new(Intent::with_version(1, IntentScope::TransactionData), data),
+    )]);
+    assert_eq!(plain.content_hash(), other_intent.content_hash());
+    assert_ne!(plain.digest(), other_intent.digest());
+    assert_ne!(plain.digest(), plain.content_hash());
+}
+
+#[test]
+fn fixture_round_trips_a_signed_transaction() {
+    let tx = Transaction::from_transaction_data(
+        transaction_data(vec![owned(3)], vec![move_call("m", "f", vec![pure_u64(1)])]),
+        vec![signature(0x66)],
+    );
+    assert_eq!(Transaction::from_fixture(&tx.to_fixture()).unwrap(), tx);
+}
+
+#[test]
+fn fixture_detects_a_corrupted_payload() {
+    let mut fixture = transaction(transaction_data(vec![owned(3)], vec![])).to_fixture();
+    let last = fixture.len() - 1;
+    fixture[last] ^= 0x01;
+
+    let err = Transaction::from_fixture(&fixture).unwrap_err();
+    assert!(err.to_string().contains("checksum mismatch"), "{}", err);
+    assert!(Transaction::from_fixture(&fixture[..fixture.len() - 1]).is_err());
+    assert!(Transaction::from_fixture(&[1, 0]).is_err());
+}
+
+#[test]
+fn log_summary_of_a_huge_transaction_stays_under_the_cap() {
+    let modules: Vec<CallArg> = (0..64)
+        .map(|_| CallArg::new_pure(vec![0xab; 16 * 1024]))
+        .collect();
+    let tx = transaction(transaction_data(modules, vec![move_call("m", "f", vec![])]));
+
+    let full = tx.to_log_summary(usize::MAX);
+    assert!(full.len() < 512, "{}", full);
+    assert!(full.contains("commands: 1"), "{}", full);
+    assert!(full.contains(&tx.data().digest().to_string()), "{}", full);
+    assert!(!full.contains("abab"), "{}", full);
+
+    let capped = tx.to_log_summary(40);
+    assert_eq!(capped.len(), 40);
+    assert!(full.starts_with(&capped));
+}
+
+#[test]
+fn sign_with_stores_the_external_signature() {
+    use ed25519_dalek::{Signer, Verifier};
+
+    let secret = [3; 32];
+    let signing_key = ed25519_dalek::SigningKey::from_bytes(&secret);
+    let public_key = SuiKeyPair::Ed25519(secret.to_vec()).public();
+    let data = transaction_data(vec![owned(3)], vec![]);
+
+    let tx = Transaction::sign_with(data.clone(), public_key, |bytes| {
+        Ok(signing_key.sign(bytes).to_vec())
+    })
+    .unwrap();
+
+    assert!(tx.is_signed());
+    assert_eq!(tx.data().transactions[0].intent_message.value, data);
+    let [stored] = &tx.auth_signature()[..] else {
+        panic!("expected one signature");
+    };
+    assert_eq!(stored.scheme, crypto::SignatureScheme::ED25519);
+    let signature = ed25519_dalek::Signature::from_slice(&stored.signature_bytes).unwrap();
+    let signing_bytes = data.intent_message().signing_bytes().unwrap();
+    assert!(signing_key
+        .verifying_key()
+        .verify(&signing_bytes, &signature)
+        .is_ok());
+}
+
+#[test]
+fn sign_with_propagates_signer_failures() {
+    let public_key = SuiKeyPair::Ed25519(vec![3; 32]).public();
+    let data = transaction_data(vec![], vec![]);
+
+    let refused = Transaction::sign_with(data.clone(), public_key.clone(), |_| {
+        Err(eyre::eyre!("device locked"))
+    });
+    assert_eq!(refused.unwrap_err().to_string(), "device locked");
+    assert!(Transaction::sign_with(data, public_key, |_| Ok(vec![])).is_err());
+}
+
+#[test]
+fn tx_serializer_matches_one_shot_encoding_across_reuse() {
+    let mut serializer = minimal_sui_types::transaction::TxSerializer::new();
+    let big = transaction_data(vec![CallArg::new_pure(vec![7; 4_096])], vec![]);
+    let small = transaction_data(vec![owned(3)], vec![]);
+
+    for data in [&big, &small, &big] {
+        assert_eq!(
+            serializer.serialize(data),
+            &bcs::to_bytes(data).unwrap()[..]
+        );
+    }
+    for data in [small, big] {
+        let signed = transaction(data).into_data();
+        assert_eq!(serializer.digest(&signed), signed.digest());
+    }
+}
+
+#[test]
+fn map_data_keeps_the_signatures() {
+    let tx = Transaction::from_transaction_data(
+        transaction_data(vec![owned(3)], vec![]),
+        vec![signature(0x66)],
+    );
+    assert_eq!(tx.clone().map_data(|data| data), tx);
+
+    let second = signed_transaction(transaction_data(vec![owned(4)], vec![]));
+    let batched = tx.clone().map_data(|mut data| {
+        data.transactions.push(second.clone());
+        data
+    });
+    assert_eq!(batched.data().len(), 2);
+    assert_eq!(batched.auth_signature(), tx.auth_signature());
+}
+
+#[test]
+fn envelopes_report_their_signature_count() {
+    let data = transaction(transaction_data(vec![owned(3)], vec![])).into_data();
+
+    let unsigned = Envelope::new_from_data_and_sig(data.clone(), EmptySignInfo {});
+    assert!(!unsigned.is_signed());
+    assert_eq!(unsigned.signature_count(), 0);
+
+    let empty = Transaction::new_from_data_and_sig(data.clone(), vec![]);
+    assert!(!empty.is_signed());
+
+    let signed = Transaction::new_from_data_and_sig(data, vec![signature(1), signature(2)]);
+    assert!(signed.is_signed());
+    assert_eq!(signed.signature_count(), 2);
+}
+
+#[test]
+fn sign_with_rejects_unresolved_placeholders_before_signing() {
+    let public_key = SuiKeyPair::Ed25519(vec![3; 32]).public();
+    let data = transaction_data(vec![CallArg::unresolved_object(object_id(5))], vec![]);
+
+    let result = Transaction::sign_with(data, public_key, |_| -> Result<Vec<u8>, eyre::Report> {
+        panic!("signer must not see unresolved data")
+    });
+    assert!(result.unwrap_err().to_string().contains("unresolved"));
+}
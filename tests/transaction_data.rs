@@ -0,0 +1,278 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+mod common;
+
+use common::*;
+use minimal_sui_types::transaction::{ObjectRole, TRANSACTION_DATA_FORMAT_VERSION};
+use minimal_sui_types::*;
+
+#[test]
+fn with_sender_changes_only_sender_and_gas_owner() {
+    let original = transaction_data(vec![owned(3)], vec![move_call("m", "f", vec![])]);
+    let moved = original.clone().with_sender(address(2));
+
+    assert_eq!(moved.sender, address(2));
+    assert_eq!(moved.gas_data.owner, address(2));
+    assert_eq!(moved.kind, original.kind);
+    assert_eq!(moved.gas_data.payment, original.gas_data.payment);
+    assert_eq!(moved.gas_data.budget, original.gas_data.budget);
+    assert_eq!(moved.gas_data.price, original.gas_data.price);
+    assert_eq!(moved.expiration, original.expiration);
+}
+
+#[test]
+fn with_sender_keeps_sponsor_as_gas_owner() {
+    let mut sponsored = transaction_data(vec![], vec![]);
+    sponsored.gas_data.owner = address(9);
+
+    let moved = sponsored.with_sender(address(2));
+    assert_eq!(moved.sender, address(2));
+    assert_eq!(moved.gas_data.owner, address(9));
+}
+
+#[test]
+fn versioned_bytes_round_trip_version_1() {
+    let data = transaction_data(vec![owned(3)], vec![move_call("m", "f", vec![])]);
+    let bytes = data.to_versioned_bytes();
+
+    assert_eq!(bytes[0], TRANSACTION_DATA_FORMAT_VERSION);
+    assert_eq!(&bytes[1..], &bcs::to_bytes(&data).unwrap()[..]);
+    assert_eq!(TransactionData::from_versioned_bytes(&bytes).unwrap(), data);
+}
+
+#[test]
+fn versioned_bytes_reject_unknown_version_and_empty_input() {
+    let mut bytes = transaction_data(vec![], vec![]).to_versioned_bytes();
+    bytes[0] = 0xff;
+
+    assert!(TransactionData::from_versioned_bytes(&bytes).is_err());
+    assert!(TransactionData::from_versioned_bytes(&[]).is_err());
+}
+
+#[test]
+fn bcs_size_hint_is_at_least_the_encoded_size() {
+    let mut with_expiration = transaction_data(vec![owned(3)], vec![]);
+    with_expiration.expiration = TransactionExpiration::new_epoch(9);
+    let samples = vec![
+        transaction_data(vec![], vec![]),
+        transaction_data(
+            vec![owned(3), shared(4, true), pure_u64(1)],
+            vec![move_call("m", "f", vec![pure_u64(2), owned(5)])],
+        ),
+        transaction_data(vec![CallArg::new_pure(vec![7; 300])], vec![]),
+        with_expiration,
+    ];
+    for data in samples {
+        let bytes = data.to_bcs_bytes().unwrap();
+        assert_eq!(bytes, bcs::to_bytes(&data).unwrap());
+        assert!(data.bcs_size_hint() >= bytes.len());
+    }
+}
+
+#[test]
+fn same_intent_ignores_gas_but_equality_does_not() {
+    let original = transaction_data(vec![owned(3)], vec![move_call("m", "f", vec![])]);
+    let mut regassed = original.clone();
+    regassed.gas_data.payment = vec![object_ref(0x98, 4)];
+
+    assert!(original.same_intent(&regassed));
+    assert_ne!(original, regassed);
+
+    let other_sender = original.clone().with_sender(address(2));
+    assert!(!original.same_intent(&other_sender));
+}
+
+#[test]
+fn display_json_keys_keep_a_stable_order() {
+    let data = transaction_data(
+        vec![],
+        vec![
+            move_call("coin", "split", vec![pure_u64(5)]),
+            move_call("transfer", "public_transfer", vec![owned(3)]),
+        ],
+    );
+    let json = data.to_display_json();
+
+    let keys = |value: &serde_json::Value| {
+        value
+            .as_object()
+            .unwrap()
+            .keys()
+            .cloned()
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(keys(&json), ["sender", "gas", "expiration", "commands"]);
+    assert_eq!(keys(&json["gas"]), ["budget", "price", "coins"]);
+    let commands = json["commands"].as_array().unwrap();
+    assert_eq!(commands.len(), 2);
+    assert_eq!(
+        keys(&commands[1]["moveCall"]),
+        ["target", "typeArguments", "arguments"]
+    );
+    assert_eq!(
+        commands[0]["moveCall"]["arguments"][0]["pure"],
+        "0x0500000000000000"
+    );
+    assert_eq!(commands[1]["moveCall"]["arguments"][0]["version"], 1);
+    // Re-rendering produces byte-identical output
+    assert_eq!(json.to_string(), data.to_display_json().to_string());
+}
+
+#[test]
+fn bump_object_versions_advances_owned_objects_only() {
+    let mut data = transaction_data(
+        vec![owned(3), shared(4, true), pure_u64(1)],
+        vec![move_call("m", "f", vec![owned(5)])],
+    );
+    let original = data.clone();
+    data.bump_object_versions(10);
+
+    let inputs = &programmable(&data).inputs;
+    assert_eq!(
+        inputs[0],
+        CallArg::new_object(ObjectArg::new_imm_or_owned(object_ref(3, 11)))
+    );
+    assert_eq!(inputs[1], programmable(&original).inputs[1]);
+    assert_eq!(inputs[2], pure_u64(1));
+    let Command::MoveCall(call) = &programmable(&data).commands[0];
+    assert_eq!(
+        call.arguments[0],
+        CallArg::new_object(ObjectArg::new_imm_or_owned(object_ref(5, 11)))
+    );
+    assert_eq!(data.gas_data.payment, vec![object_ref(0x99, 11)]);
+}
+
+#[test]
+fn epoch_expiration_applies_only_after_its_epoch() {
+    let mut data = transaction_data(vec![], vec![]);
+    data.expiration = TransactionExpiration::new_epoch(10);
+
+    assert_eq!(data.expiration_epoch(), Some(10));
+    assert!(!data.is_expired_at(9));
+    assert!(!data.is_expired_at(10));
+    assert!(data.is_expired_at(11));
+}
+
+#[test]
+fn transaction_without_expiration_never_expires() {
+    let data = transaction_data(vec![], vec![]);
+
+    assert_eq!(data.expiration_epoch(), None);
+    assert!(!data.is_expired_at(0));
+    assert!(!data.is_expired_at(u64::MAX));
+}
+
+#[test]
+fn object_input_roles_label_gas_owned_and_shared_inputs() {
+    let data = transaction_data(
+        vec![owned(3), shared(4, true), pure_u64(1)],
+        vec![move_call("m", "read", vec![shared(5, false)])],
+    );
+
+    assert_eq!(
+        data.object_input_roles(),
+        vec![
+            (object_id(0x99), ObjectRole::Gas),
+            (object_id(3), ObjectRole::Owned),
+            (object_id(4), ObjectRole::SharedMutable),
+            (object_id(5), ObjectRole::SharedReadOnly),
+        ]
+    );
+}
+
+#[test]
+fn equivalent_gas_payments_canonicalize_to_identical_bytes() {
+    let mut first = transaction_data(vec![owned(3)], vec![move_call("m", "f", vec![])]);
+    first.gas_data.payment = vec![
+        object_ref(0x99, 1),
+        object_ref(0x92, 1),
+        object_ref(0x95, 1),
+    ];
+    let mut second = first.clone();
+    second.gas_data.payment = vec![
+        object_ref(0x99, 1),
+        object_ref(0x95, 1),
+        object_ref(0x92, 1),
+    ];
+    assert_ne!(
+        first.to_bcs_bytes().unwrap(),
+        second.to_bcs_bytes().unwrap()
+    );
+
+    first.canonicalize_full();
+    second.canonicalize_full();
+    assert_eq!(
+        first.to_bcs_bytes().unwrap(),
+        second.to_bcs_bytes().unwrap()
+    );
+    // The primary coin that the others are smashed into must not move
+    assert_eq!(
+        first.gas_data.payment,
+        vec![
+            object_ref(0x99, 1),
+            object_ref(0x92, 1),
+            object_ref(0x95, 1)
+        ]
+    );
+}
+
+#[test]
+fn canonicalize_full_keeps_repeated_inputs() {
+    let mut data = transaction_data(vec![pure_u64(1), pure_u64(1)], vec![]);
+    data.canonicalize_full();
+    assert_eq!(programmable(&data).inputs, vec![pure_u64(1), pure_u64(1)]);
+}
+
+#[test]
+fn assert_matches_bytes_accepts_an_identical_encoding() {
+    let data = transaction_data(vec![owned(3)], vec![move_call("m", "f", vec![])]);
+    assert_eq!(
+        data.assert_matches_bytes(&bcs::to_bytes(&data).unwrap()),
+        Ok(())
+    );
+}
+
+#[test]
+fn assert_matches_bytes_reports_the_differing_fields() {
+    let data = transaction_data(vec![owned(3)], vec![]);
+    let mut node = data.clone();
+    node.gas_data.budget = 20_000_000;
+    node.expiration = TransactionExpiration::new_epoch(5);
+
+    let diffs = data
+        .assert_matches_bytes(&bcs::to_bytes(&node).unwrap())
+        .unwrap_err();
+    assert_eq!(diffs.len(), 2, "{:?}", diffs);
+    assert_eq!(diffs[0], "gas_data.budget: 10000000 != 20000000");
+    assert!(diffs[1].starts_with("expiration: "), "{:?}", diffs);
+
+    let garbage = data.assert_matches_bytes(&[0xff]).unwrap_err();
+    assert!(
+        garbage[0].contains("not a TransactionData"),
+        "{:?}",
+        garbage
+    );
+}
+
+#[test]
+fn with_expiration_changes_only_the_expiration() {
+    let original = transaction_data(vec![owned(3)], vec![move_call("m", "f", vec![])]);
+    let extended = original
+        .clone()
+        .with_expiration(TransactionExpiration::new_epoch(12));
+
+    assert_eq!(extended.expiration, TransactionExpiration::new_epoch(12));
+    assert_eq!(
+        TransactionData {
+            expiration: TransactionExpiration::None,
+            ..extended.clone()
+        },
+        original
+    );
+    assert_ne!(original.signing_digest(), extended.signing_digest());
+
+    let mut cleared = extended;
+    cleared.clear_expiration();
+    assert_eq!(cleared, original);
+}
@@ -0,0 +1,111 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+mod common;
+
+use common::*;
+use minimal_sui_types::transaction::StructTagInterner;
+use minimal_sui_types::*;
+use std::str::FromStr;
+
+#[test]
+fn generic_call_from_rpc_type_argument_strings() {
+    let call = MoveCall::with_type_args_str(
+        ObjectID::new(SUI_FRAMEWORK),
+        "coin",
+        "zero",
+        &["0x2::sui::SUI"],
+        vec![],
+    )
+    .unwrap();
+
+    assert_eq!(
+        call.type_arguments,
+        vec![TypeTag::new_struct(StructTag::sui_type())]
+    );
+}
+
+#[test]
+fn type_tag_parses_nested_generics_and_vectors() {
+    let tag = TypeTag::from_str("vector<0x2::coin::Coin<0x2::sui::SUI>>").unwrap();
+    let coin = StructTag::new(
+        SUI_FRAMEWORK,
+        "coin".to_string(),
+        "Coin".to_string(),
+        vec![TypeTag::new_struct(StructTag::sui_type())],
+    );
+
+    assert_eq!(tag, TypeTag::new_vector(TypeTag::new_struct(coin)));
+    assert_eq!(TypeTag::from_str(&tag.to_string()).unwrap(), tag);
+}
+
+#[test]
+fn invalid_type_argument_reports_its_index() {
+    let err = MoveCall::with_type_args_str(
+        ObjectID::new(SUI_FRAMEWORK),
+        "coin",
+        "zero",
+        &["u64", "0x2::coin"],
+        vec![],
+    )
+    .unwrap_err();
+    assert!(
+        err.to_string().contains("Invalid type argument 1"),
+        "{}",
+        err
+    );
+}
+
+#[test]
+fn interner_assigns_one_id_per_distinct_tag() {
+    let mut interner = StructTagInterner::new();
+    let sui = interner.intern(StructTag::sui_type());
+    let again = interner.intern(StructTag::sui_type());
+    let usdc = interner.intern(StructTag::from_str("0x5::usdc::USDC").unwrap());
+
+    assert_eq!(sui, again);
+    assert_ne!(sui, usdc);
+    assert_eq!(interner.len(), 2);
+    assert_eq!(interner.resolve(sui), Some(&StructTag::sui_type()));
+    assert_eq!(interner.resolve(2), None);
+}
+
+#[test]
+fn primary_coin_types_finds_the_coin_in_a_transfer() {
+    let usdc = StructTag::from_str(
+        "0xdba34672e30cb065b1f93e3ab55318768fd6fef66c15942c9f7cb846e2f900e7::usdc::USDC",
+    )
+    .unwrap();
+    let coin = TypeTag::from_str(&format!("0x2::coin::Coin<{}>", usdc)).unwrap();
+    let transfer = Command::new_move_call(MoveCall::new_with_str(
+        ObjectID::new(SUI_FRAMEWORK),
+        "transfer",
+        "public_transfer",
+        vec![coin],
+        vec![owned(3), pure_u64(1)],
+    ));
+
+    let pt = ProgrammableTransaction::new(vec![], vec![transfer]);
+    assert_eq!(pt.primary_coin_types(), vec![usdc]);
+
+    let gas_only = ProgrammableTransaction::new(vec![], vec![move_call("m", "f", vec![])]);
+    assert_eq!(gas_only.primary_coin_types(), vec![StructTag::sui_type()]);
+}
+
+const USDC: &str = "0xdba34672e30cb065b1f93e3ab55318768fd6fef66c15942c9f7cb846e2f900e7::usdc::USDC";
+
+#[test]
+fn parse_coin_type_accepts_bare_and_wrapped_forms() {
+    let usdc = StructTag::from_str(USDC).unwrap();
+
+    assert_eq!(StructTag::parse_coin_type(USDC).unwrap(), usdc);
+    assert_eq!(
+        StructTag::parse_coin_type(&format!("0x2::coin::Coin<{}>", USDC)).unwrap(),
+        usdc
+    );
+    assert_eq!(
+        StructTag::parse_coin_type("0x2::coin::Coin<0x2::sui::SUI>").unwrap(),
+        StructTag::sui_type()
+    );
+    assert!(StructTag::parse_coin_type("u64").is_err());
+}
@@ -0,0 +1,160 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+mod common;
+
+use common::*;
+use minimal_sui_types::limits::ProtocolLimits;
+use minimal_sui_types::transaction::transactions_conflict;
+use minimal_sui_types::*;
+
+#[test]
+fn validate_accepts_a_well_formed_transaction() {
+    let tx = transaction(transaction_data(
+        vec![owned(3)],
+        vec![move_call("m", "f", vec![pure_u64(1)])],
+    ));
+    assert_eq!(tx.validate(1_000, &ProtocolLimits::default()), Ok(()));
+}
+
+#[test]
+fn validate_reports_every_failed_check() {
+    let mut data = transaction_data(vec![], vec![]);
+    data.gas_data.payment.clear();
+    data.gas_data.price = 500;
+
+    let errors = transaction(data)
+        .validate(1_000, &ProtocolLimits::default())
+        .unwrap_err();
+    assert_eq!(
+        errors,
+        vec![
+            TransactionError::EmptyGasPayment,
+            TransactionError::GasPriceBelowReference {
+                price: 500,
+                reference: 1_000,
+            },
+        ]
+    );
+}
+
+#[test]
+fn gas_coin_passed_as_move_call_argument_is_a_duplicate() {
+    let gas_coin = CallArg::new_object(ObjectArg::new_imm_or_owned(object_ref(0x99, 1)));
+    let data = transaction_data(vec![], vec![move_call("m", "f", vec![gas_coin])]);
+
+    assert_eq!(
+        data.check_against(&ProtocolLimits::default()),
+        Err(vec![TransactionError::DuplicateObject(object_id(0x99))])
+    );
+}
+
+#[test]
+fn owned_object_repeated_across_inputs_is_a_duplicate() {
+    let data = transaction_data(vec![owned(3), owned(3)], vec![]);
+
+    assert_eq!(
+        data.check_against(&ProtocolLimits::default()),
+        Err(vec![TransactionError::DuplicateObject(object_id(3))])
+    );
+}
+
+#[test]
+fn transaction_errors_display_the_offending_values() {
+    let error = TransactionError::GasBudgetOutOfRange {
+        budget: 1,
+        min: 2,
+        max: 3,
+    };
+    assert_eq!(error.to_string(), "Gas budget 1 is outside [2, 3]");
+}
+
+#[test]
+fn custom_limits_override_the_defaults() {
+    let data = transaction_data(
+        vec![CallArg::new_pure(vec![0; 64])],
+        vec![move_call("m", "f", vec![]), move_call("m", "g", vec![])],
+    );
+    let limits = ProtocolLimits {
+        max_pure_argument_size: 32,
+        max_programmable_tx_commands: 1,
+        min_gas_budget: 20_000_000,
+        ..ProtocolLimits::default()
+    };
+
+    assert_eq!(data.check_against(&ProtocolLimits::default()), Ok(()));
+    assert_eq!(
+        data.check_against(&limits),
+        Err(vec![
+            TransactionError::TooManyCommands { count: 2, max: 1 },
+            TransactionError::PureArgumentTooLarge { size: 64, max: 32 },
+            TransactionError::GasBudgetOutOfRange {
+                budget: 10_000_000,
+                min: 20_000_000,
+                max: limits.max_gas_budget,
+            },
+        ])
+    );
+}
+
+/// A transaction paying with its own gas coin `gas`
+fn paid_with(gas: u8, inputs: Vec<CallArg>) -> TransactionData {
+    let mut data = transaction_data(inputs, vec![]);
+    data.gas_data.payment = vec![object_ref(gas, 1)];
+    data
+}
+
+#[test]
+fn transactions_sharing_an_owned_object_or_gas_coin_conflict() {
+    assert!(transactions_conflict(
+        &paid_with(0x90, vec![owned(3)]),
+        &paid_with(0x91, vec![owned(3)]),
+    ));
+    assert!(transactions_conflict(
+        &paid_with(0x90, vec![]),
+        &paid_with(0x91, vec![owned(0x90)]),
+    ));
+}
+
+#[test]
+fn mutable_shared_access_conflicts_but_shared_reads_do_not() {
+    let write = paid_with(0x90, vec![shared(4, true)]);
+    let read = paid_with(0x91, vec![shared(4, false)]);
+    let other_read = paid_with(0x92, vec![shared(4, false)]);
+
+    assert!(transactions_conflict(&write, &read));
+    assert!(transactions_conflict(&read, &write));
+    assert!(!transactions_conflict(&read, &other_read));
+}
+
+#[test]
+fn transactions_on_disjoint_objects_do_not_conflict() {
+    assert!(!transactions_conflict(
+        &paid_with(0x90, vec![owned(3), shared(5, true)]),
+        &paid_with(0x91, vec![owned(4), shared(6, true)]),
+    ));
+}
+
+#[test]
+fn transaction_passing_lenient_limits_fails_strict_ones() {
+    let data = transaction_data(
+        vec![CallArg::new_pure(vec![0; 2_000])],
+        vec![move_call("m", "f", vec![])],
+    );
+    let lenient = ProtocolLimits {
+        max_tx_size_bytes: 4_096,
+        ..ProtocolLimits::default()
+    };
+    let strict = ProtocolLimits {
+        max_tx_size_bytes: 1_024,
+        ..lenient.clone()
+    };
+
+    assert_eq!(data.check_against(&lenient), Ok(()));
+    let errors = data.check_against(&strict).unwrap_err();
+    assert!(
+        matches!(errors[..], [TransactionError::TooLarge { max: 1_024, .. }]),
+        "{:?}",
+        errors
+    );
+}
@@ -0,0 +1,46 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+#![cfg(feature = "verbose-json")]
+
+mod common;
+
+use common::*;
+use minimal_sui_types::*;
+
+#[test]
+fn verbose_json_round_trips_every_argument_kind() {
+    let mut data = transaction_data(
+        vec![owned(3), shared(4, true), pure_u64(7)],
+        vec![move_call(
+            "m",
+            "f",
+            vec![CallArg::UnresolvedObject(object_id(5)), owned(6)],
+        )],
+    );
+    data.expiration = TransactionExpiration::new_epoch(9);
+
+    let json = data.to_verbose_json();
+    assert_eq!(TransactionData::from_verbose_json(&json).unwrap(), data);
+}
+
+#[test]
+fn verbose_json_uses_camel_case_hex_and_string_amounts() {
+    let json = transaction_data(vec![shared(4, true)], vec![]).to_verbose_json();
+
+    assert_eq!(json["sender"], address(1).to_string());
+    assert_eq!(json["gasData"]["budget"], "10000000");
+    assert_eq!(json["gasData"]["price"], "1000");
+    assert_eq!(json["gasData"]["payment"][0]["version"], "1");
+    let input = &json["kind"]["programmableTransaction"]["inputs"][0];
+    assert_eq!(input["type"], "sharedObject");
+    assert_eq!(input["objectId"], object_id(4).to_string());
+    assert_eq!(input["initialSharedVersion"], "1");
+}
+
+#[test]
+fn from_verbose_json_rejects_a_malformed_amount() {
+    let mut json = transaction_data(vec![], vec![]).to_verbose_json();
+    json["gasData"]["budget"] = "ten".into();
+    assert!(TransactionData::from_verbose_json(&json).is_err());
+}